@@ -0,0 +1,244 @@
+//! # Strongly-Connected-Component Condensation
+//!
+//! Computes the strongly connected components (SCCs) of a directed graph view using Tarjan's
+//! algorithm, and collapses each component into a single super-vertex of a new [`Graph`],
+//! yielding a first-class, re-queryable structural simplification of the original graph.
+use crate::{
+    core::{entities::VID, Prop},
+    db::graph::{graph::Graph, vertex::VertexView},
+    prelude::{AdditionOps, EdgeViewOps, GraphViewOps, VertexViewOps, NO_PROPS},
+};
+use std::collections::HashMap;
+
+/// Tarjan's algorithm: DFS assigning each vertex a discovery `index` and `lowlink`, pushing
+/// vertices onto a stack, and popping a component whenever `lowlink == index` for the vertex
+/// that started it.
+struct Tarjan<G> {
+    graph: G,
+    index_counter: usize,
+    index: HashMap<VID, usize>,
+    lowlink: HashMap<VID, usize>,
+    on_stack: HashMap<VID, bool>,
+    stack: Vec<VID>,
+    components: Vec<Vec<VID>>,
+}
+
+impl<G: GraphViewOps> Tarjan<G> {
+    fn new(graph: G) -> Self {
+        Self {
+            graph,
+            index_counter: 0,
+            index: HashMap::new(),
+            lowlink: HashMap::new(),
+            on_stack: HashMap::new(),
+            stack: Vec::new(),
+            components: Vec::new(),
+        }
+    }
+
+    fn run(&mut self) {
+        let vertices: Vec<VID> = self.graph.vertices().iter().map(|v| v.vertex).collect();
+        for v in vertices {
+            if !self.index.contains_key(&v) {
+                self.strong_connect(v);
+            }
+        }
+    }
+
+    fn strong_connect(&mut self, v: VID) {
+        self.index.insert(v, self.index_counter);
+        self.lowlink.insert(v, self.index_counter);
+        self.index_counter += 1;
+        self.stack.push(v);
+        self.on_stack.insert(v, true);
+
+        let v_view = VertexView::new_internal(self.graph.clone(), v);
+        let successors: Vec<VID> = v_view.out_neighbours().iter().map(|n| n.vertex).collect();
+        for w in successors {
+            if !self.index.contains_key(&w) {
+                self.strong_connect(w);
+                let w_low = self.lowlink[&w];
+                let v_low = self.lowlink[&v];
+                self.lowlink.insert(v, v_low.min(w_low));
+            } else if *self.on_stack.get(&w).unwrap_or(&false) {
+                let w_idx = self.index[&w];
+                let v_low = self.lowlink[&v];
+                self.lowlink.insert(v, v_low.min(w_idx));
+            }
+        }
+
+        if self.lowlink[&v] == self.index[&v] {
+            let mut component = Vec::new();
+            loop {
+                let w = self.stack.pop().expect("stack must not be empty");
+                self.on_stack.insert(w, false);
+                component.push(w);
+                if w == v {
+                    break;
+                }
+            }
+            self.components.push(component);
+        }
+    }
+}
+
+/// Computes the strongly connected components of `graph` and returns them as groups of vertex
+/// ids, in the order Tarjan's algorithm discovers them.
+pub fn strongly_connected_components<G: GraphViewOps>(graph: &G) -> Vec<Vec<u64>> {
+    let mut tarjan = Tarjan::new(graph.clone());
+    tarjan.run();
+    tarjan
+        .components
+        .into_iter()
+        .map(|component| {
+            component
+                .into_iter()
+                .map(|vid| VertexView::new_internal(graph.clone(), vid).id())
+                .collect()
+        })
+        .collect()
+}
+
+/// Computes the SCC condensation of `graph` and returns a new [`Graph`] whose vertices are the
+/// components. Each super-vertex carries the collapsed member ids as a `members` list property.
+/// `keep_self_loops` controls whether an edge between two members of the same component is
+/// preserved as a self-loop on the resulting super-vertex (it is otherwise dropped), and
+/// parallel inter-component edges are merged into a single edge.
+pub fn condensation<G: GraphViewOps>(graph: &G, keep_self_loops: bool) -> Graph {
+    let components = strongly_connected_components(graph);
+    let mut member_to_component: HashMap<u64, usize> = HashMap::new();
+    for (idx, component) in components.iter().enumerate() {
+        for &member in component {
+            member_to_component.insert(member, idx);
+        }
+    }
+
+    let condensed = Graph::new();
+    for (idx, component) in components.iter().enumerate() {
+        condensed
+            .add_vertex(0, idx as u64, NO_PROPS)
+            .expect("adding a super-vertex should not fail");
+        condensed
+            .vertex(idx as u64)
+            .expect("super-vertex was just added")
+            .add_constant_properties([(
+                "members".to_string(),
+                Prop::List(component.iter().map(|&m| Prop::U64(m)).collect::<Vec<_>>().into()),
+            )])
+            .expect("adding the members property should not fail");
+    }
+
+    let mut seen_edges = std::collections::HashSet::new();
+    for edge in graph.edges() {
+        let src_comp = member_to_component[&edge.src().id()];
+        let dst_comp = member_to_component[&edge.dst().id()];
+        if src_comp == dst_comp && !keep_self_loops {
+            continue;
+        }
+        if !seen_edges.insert((src_comp, dst_comp)) {
+            continue;
+        }
+        condensed
+            .add_edge(0, src_comp as u64, dst_comp as u64, NO_PROPS, None)
+            .expect("adding a condensed edge should not fail");
+    }
+
+    condensed
+}
+
+#[cfg(test)]
+mod test {
+    use super::{condensation, strongly_connected_components};
+    use crate::{
+        core::Prop,
+        db::{api::mutation::AdditionOps, graph::graph::Graph},
+        prelude::*,
+    };
+    use std::collections::{BTreeSet, HashSet};
+
+    // Two cycles a -> b -> c -> a and d -> e -> d, bridged by a single c -> d edge, so the SCCs
+    // are exactly {a, b, c} and {d, e}.
+    fn two_cycles_graph() -> Graph {
+        let g = Graph::new();
+        for (src, dst) in [
+            ("a", "b"),
+            ("b", "c"),
+            ("c", "a"),
+            ("c", "d"),
+            ("d", "e"),
+            ("e", "d"),
+        ] {
+            g.add_edge(0, src, dst, NO_PROPS, None).unwrap();
+        }
+        g
+    }
+
+    fn as_id_sets(groups: Vec<Vec<u64>>) -> HashSet<BTreeSet<u64>> {
+        groups.into_iter().map(|g| g.into_iter().collect()).collect()
+    }
+
+    #[test]
+    fn finds_the_two_cycles_as_separate_components() {
+        let g = two_cycles_graph();
+        let components = strongly_connected_components(&g);
+
+        let a = g.node("a").unwrap().id();
+        let b = g.node("b").unwrap().id();
+        let c = g.node("c").unwrap().id();
+        let d = g.node("d").unwrap().id();
+        let e = g.node("e").unwrap().id();
+
+        let mut expected = HashSet::new();
+        expected.insert(BTreeSet::from([a, b, c]));
+        expected.insert(BTreeSet::from([d, e]));
+        assert_eq!(as_id_sets(components), expected);
+    }
+
+    #[test]
+    fn condensation_collapses_each_cycle_into_one_bridged_super_vertex() {
+        let g = two_cycles_graph();
+        let condensed = condensation(&g, false);
+
+        assert_eq!(condensed.num_vertices(), 2);
+        assert_eq!(condensed.num_edges(), 1);
+
+        let a = g.node("a").unwrap().id();
+        let b = g.node("b").unwrap().id();
+        let c = g.node("c").unwrap().id();
+        let d = g.node("d").unwrap().id();
+        let e = g.node("e").unwrap().id();
+
+        let member_sets: HashSet<BTreeSet<u64>> = condensed
+            .vertices()
+            .iter()
+            .map(|v| {
+                let Some(Prop::List(members)) = v.properties().constant().get("members") else {
+                    panic!("members property must be a list");
+                };
+                members
+                    .iter()
+                    .filter_map(|m| match m {
+                        Prop::U64(id) => Some(*id),
+                        _ => None,
+                    })
+                    .collect::<BTreeSet<u64>>()
+            })
+            .collect();
+
+        let mut expected = HashSet::new();
+        expected.insert(BTreeSet::from([a, b, c]));
+        expected.insert(BTreeSet::from([d, e]));
+        assert_eq!(member_sets, expected);
+    }
+
+    #[test]
+    fn keep_self_loops_preserves_an_edge_within_a_collapsed_component() {
+        let g = two_cycles_graph();
+        let condensed = condensation(&g, true);
+
+        // With self-loops kept, each cycle additionally contributes one self-loop on its own
+        // super-vertex (deduplicated from its multiple internal edges), on top of the bridging
+        // c -> d edge, so the edge count grows from 1 to 3.
+        assert_eq!(condensed.num_edges(), 3);
+    }
+}
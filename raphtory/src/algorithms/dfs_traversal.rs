@@ -0,0 +1,186 @@
+//! # Cycle-Safe DFS Traversal
+//!
+//! A depth-first traversal over a graph view that is safe on cyclic (including temporal) graphs
+//! and can additionally report the back edges that witness a cycle, using the classic
+//! three-colour scheme: a node is [`Color::White`] (undiscovered), [`Color::Gray`] (on the
+//! current DFS stack) or [`Color::Black`] (finished, all descendants exhausted). Descending into
+//! a `Gray` neighbour would revisit a node already on the stack, so instead of recursing the walk
+//! reports it as a back edge.
+//!
+//! [`dfs_with_cycles_from_ops`] is the primitive requested against [`GraphOps`] directly, walking
+//! `neighbours(v, Direction::OUT, layers, filter)`; it is also wired up as
+//! [`GraphOps::dfs_with_cycles`] (`db/api/view/internal/graph_ops.rs`). [`dfs_with_cycles`] is the
+//! view-layer convenience built on top of it for the common "traverse everything this view
+//! currently shows" case, and is what `DfsTraversalOps` exposes fluently on a graph view.
+use crate::{
+    core::entities::{LayerIds, VID},
+    db::{
+        api::view::{
+            internal::{EdgeFilter, GraphOps},
+            StaticGraphViewOps,
+        },
+        graph::vertex::VertexView,
+    },
+    prelude::{GraphViewOps, NodeViewOps},
+};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Color {
+    White,
+    Gray,
+    Black,
+}
+
+/// An explicit-stack DFS frame: the node being expanded and an iterator position into its
+/// out-neighbours, so descending doesn't recurse the call stack.
+struct Frame {
+    node: VID,
+    neighbours: std::vec::IntoIter<VID>,
+}
+
+/// Walks `graph` depth-first from `start`, returning the nodes in discovery order together with
+/// every back edge `(u, v)` found along the way, i.e. an edge from the node currently being
+/// expanded to a node still `Gray` on the stack. A non-empty list of back edges means the
+/// traversal encountered a cycle.
+pub fn dfs_with_cycles_from_ops<'graph, G: GraphOps<'graph> + ?Sized>(
+    graph: &G,
+    start: VID,
+    layers: &LayerIds,
+    filter: Option<&EdgeFilter>,
+) -> (Vec<VID>, Vec<(VID, VID)>) {
+    let mut color: HashMap<VID, Color> = HashMap::new();
+    let mut order = Vec::new();
+    let mut back_edges = Vec::new();
+
+    let out_neighbours = |v: VID| -> std::vec::IntoIter<VID> {
+        graph
+            .neighbours(v, crate::core::Direction::OUT, layers.clone(), filter)
+            .collect::<Vec<_>>()
+            .into_iter()
+    };
+
+    color.insert(start, Color::Gray);
+    order.push(start);
+    let mut stack = vec![Frame {
+        node: start,
+        neighbours: out_neighbours(start),
+    }];
+
+    while let Some(frame) = stack.last_mut() {
+        match frame.neighbours.next() {
+            Some(next) => match color.get(&next).copied().unwrap_or(Color::White) {
+                Color::White => {
+                    color.insert(next, Color::Gray);
+                    order.push(next);
+                    stack.push(Frame {
+                        node: next,
+                        neighbours: out_neighbours(next),
+                    });
+                }
+                Color::Gray => back_edges.push((frame.node, next)),
+                Color::Black => {}
+            },
+            None => {
+                color.insert(frame.node, Color::Black);
+                stack.pop();
+            }
+        }
+    }
+
+    (order, back_edges)
+}
+
+/// As [`dfs_with_cycles`], but discards the back edges and only returns the discovery order.
+pub fn dfs_order<G: StaticGraphViewOps>(graph: &G, start: u64) -> Vec<u64> {
+    dfs_with_cycles(graph, start).0
+}
+
+/// Runs [`dfs_with_cycles_from_ops`] over everything `graph` currently shows, translating the
+/// `u64` node id `start` to its [`VID`] and the result back to `u64`s for the view-layer API.
+pub fn dfs_with_cycles<G: StaticGraphViewOps>(graph: &G, start: u64) -> (Vec<u64>, Vec<(u64, u64)>) {
+    let Some(start_view) = graph.node(start) else {
+        return (Vec::new(), Vec::new());
+    };
+
+    let (order, back_edges) =
+        dfs_with_cycles_from_ops(graph, start_view.vertex, &LayerIds::All, None);
+
+    let to_id = |v: VID| VertexView::new_internal(graph.clone(), v).id();
+    let order = order.into_iter().map(to_id).collect();
+    let back_edges = back_edges
+        .into_iter()
+        .map(|(u, v)| (to_id(u), to_id(v)))
+        .collect();
+    (order, back_edges)
+}
+
+/// Fluent access to [`dfs_order`]/[`dfs_with_cycles`] directly on a graph view.
+pub trait DfsTraversalOps<G: StaticGraphViewOps> {
+    fn dfs_order(&self, start: u64) -> Vec<u64>;
+    fn dfs_with_cycles(&self, start: u64) -> (Vec<u64>, Vec<(u64, u64)>);
+}
+
+impl<G: StaticGraphViewOps> DfsTraversalOps<G> for G {
+    fn dfs_order(&self, start: u64) -> Vec<u64> {
+        dfs_order(self, start)
+    }
+
+    fn dfs_with_cycles(&self, start: u64) -> (Vec<u64>, Vec<(u64, u64)>) {
+        dfs_with_cycles(self, start)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{dfs_order, dfs_with_cycles};
+    use crate::{
+        db::{api::mutation::AdditionOps, graph::graph::Graph},
+        prelude::*,
+    };
+    use std::collections::HashSet;
+
+    #[test]
+    fn a_straight_line_chain_has_no_back_edges_and_visits_in_order() {
+        let g = Graph::new();
+        g.add_edge(0, "a", "b", NO_PROPS, None).unwrap();
+        g.add_edge(0, "b", "c", NO_PROPS, None).unwrap();
+
+        let a = g.node("a").unwrap().id();
+        let b = g.node("b").unwrap().id();
+        let c = g.node("c").unwrap().id();
+
+        let (order, back_edges) = dfs_with_cycles(&g, a);
+        assert_eq!(order, vec![a, b, c]);
+        assert!(back_edges.is_empty());
+    }
+
+    #[test]
+    fn a_cycle_back_to_an_ancestor_still_on_the_stack_is_reported() {
+        let g = Graph::new();
+        // a -> b -> d -> a closes a cycle back to the node the DFS started from; a -> c is a
+        // second, unrelated branch off the root.
+        g.add_edge(0, "a", "b", NO_PROPS, None).unwrap();
+        g.add_edge(0, "b", "d", NO_PROPS, None).unwrap();
+        g.add_edge(0, "d", "a", NO_PROPS, None).unwrap();
+        g.add_edge(0, "a", "c", NO_PROPS, None).unwrap();
+
+        let a = g.node("a").unwrap().id();
+        let b = g.node("b").unwrap().id();
+        let c = g.node("c").unwrap().id();
+        let d = g.node("d").unwrap().id();
+
+        let (order, back_edges) = dfs_with_cycles(&g, a);
+        let visited: HashSet<u64> = order.into_iter().collect();
+        assert_eq!(visited, HashSet::from([a, b, c, d]));
+        assert_eq!(back_edges, vec![(d, a)]);
+    }
+
+    #[test]
+    fn missing_start_node_yields_an_empty_traversal() {
+        let g = Graph::new();
+        g.add_edge(0, "a", "b", NO_PROPS, None).unwrap();
+        assert_eq!(dfs_order(&g, 999), Vec::<u64>::new());
+        assert_eq!(dfs_with_cycles(&g, 999), (Vec::new(), Vec::new()));
+    }
+}
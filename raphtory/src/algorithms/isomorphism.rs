@@ -0,0 +1,309 @@
+//! # Isomorphism Matching
+//!
+//! `graph_equal` only matches graphs with identical vertex ids. `is_isomorphic_matching` instead
+//! tests structural equivalence up to a vertex relabelling, using the VF2 algorithm, with
+//! optional closures to additionally require matching vertex/edge properties.
+use crate::{
+    core::entities::VID,
+    db::graph::{edge::EdgeView, vertex::VertexView},
+    prelude::{EdgeViewOps, GraphViewOps, VertexViewOps},
+};
+use std::collections::{HashMap, HashSet};
+
+/// VF2 search state: the partial mapping between the two graphs and the terminal sets (vertices
+/// adjacent to the current mapping on each side) used to prioritize candidate selection.
+struct State<'a, G1, G2> {
+    g1: &'a G1,
+    g2: &'a G2,
+    core_1: HashMap<VID, VID>,
+    core_2: HashMap<VID, VID>,
+    terminal_1: HashSet<VID>,
+    terminal_2: HashSet<VID>,
+}
+
+/// Tests whether `g1` and `g2` are isomorphic, with `node_match`/`edge_match` allowed to reject
+/// a vertex/edge pairing based on their properties. Short-circuits to `false` immediately if the
+/// vertex or edge counts differ.
+pub fn is_isomorphic_matching<G1, G2, NM, EM>(
+    g1: &G1,
+    g2: &G2,
+    node_match: NM,
+    edge_match: EM,
+) -> bool
+where
+    G1: GraphViewOps,
+    G2: GraphViewOps,
+    NM: Fn(&VertexView<G1>, &VertexView<G2>) -> bool,
+    EM: Fn(&EdgeView<G1, G1>, &EdgeView<G2, G2>) -> bool,
+{
+    if g1.num_vertices() != g2.num_vertices() || g1.num_edges() != g2.num_edges() {
+        return false;
+    }
+
+    let mut state = State {
+        g1,
+        g2,
+        core_1: HashMap::new(),
+        core_2: HashMap::new(),
+        terminal_1: HashSet::new(),
+        terminal_2: HashSet::new(),
+    };
+
+    let total = g1.num_vertices();
+    search(&mut state, total, &node_match, &edge_match)
+}
+
+fn vertex_at<G: GraphViewOps>(graph: &G, vid: VID) -> VertexView<G> {
+    VertexView::new_internal(graph.clone(), vid)
+}
+
+fn next_candidate_g1<G1: GraphViewOps>(state: &State<G1, impl GraphViewOps>) -> Option<VID> {
+    // Prefer an unmapped vertex already in the terminal set; otherwise take the smallest-id
+    // unmapped vertex, to keep the search deterministic.
+    let mut candidates: Vec<VID> = state
+        .terminal_1
+        .iter()
+        .copied()
+        .filter(|v| !state.core_1.contains_key(v))
+        .collect();
+    if candidates.is_empty() {
+        candidates = state
+            .g1
+            .vertices()
+            .iter()
+            .map(|v| v.vertex)
+            .filter(|v| !state.core_1.contains_key(v))
+            .collect();
+    }
+    candidates.into_iter().min_by_key(|v| v.0)
+}
+
+fn feasible<G1, G2, NM, EM>(
+    state: &State<G1, G2>,
+    n: VID,
+    m: VID,
+    node_match: &NM,
+    edge_match: &EM,
+) -> bool
+where
+    G1: GraphViewOps,
+    G2: GraphViewOps,
+    NM: Fn(&VertexView<G1>, &VertexView<G2>) -> bool,
+    EM: Fn(&EdgeView<G1, G1>, &EdgeView<G2, G2>) -> bool,
+{
+    let n_view = vertex_at(state.g1, n);
+    let m_view = vertex_at(state.g2, m);
+
+    if n_view.degree() != m_view.degree() {
+        return false;
+    }
+    if !node_match(&n_view, &m_view) {
+        return false;
+    }
+
+    // Every already-mapped neighbour of n must map to a neighbour of m, and vice versa.
+    for neighbour in n_view.neighbours().iter() {
+        if let Some(&mapped) = state.core_1.get(&neighbour.vertex) {
+            if !m_view.neighbours().iter().any(|x| x.vertex == mapped) {
+                return false;
+            }
+        }
+    }
+    for neighbour in m_view.neighbours().iter() {
+        if let Some(&mapped) = state.core_2.get(&neighbour.vertex) {
+            if !n_view.neighbours().iter().any(|x| x.vertex == mapped) {
+                return false;
+            }
+        }
+    }
+
+    // Look-ahead: counts of terminal-set and totally-unmapped neighbours must be consistent.
+    let (n_term, n_new) = look_ahead_counts(&n_view, &state.core_1, &state.terminal_1);
+    let (m_term, m_new) = look_ahead_counts(&m_view, &state.core_2, &state.terminal_2);
+    if n_term != m_term || n_new != m_new {
+        return false;
+    }
+
+    // For every edge between n and an already-mapped neighbour, in either direction, the induced
+    // edge in g2 (between m and that neighbour's image, same direction) must exist and satisfy
+    // edge_match.
+    for e in n_view.out_edges() {
+        if let Some(&mapped) = state.core_1.get(&e.dst().vertex) {
+            match m_view.out_edges().iter().find(|me| me.dst().vertex == mapped) {
+                Some(me) => {
+                    if !edge_match(&e, me) {
+                        return false;
+                    }
+                }
+                None => return false,
+            }
+        }
+    }
+    for e in n_view.in_edges() {
+        if let Some(&mapped) = state.core_1.get(&e.src().vertex) {
+            match m_view.in_edges().iter().find(|me| me.src().vertex == mapped) {
+                Some(me) => {
+                    if !edge_match(&e, me) {
+                        return false;
+                    }
+                }
+                None => return false,
+            }
+        }
+    }
+
+    true
+}
+
+fn look_ahead_counts<G: GraphViewOps>(
+    v: &VertexView<G>,
+    core: &HashMap<VID, VID>,
+    terminal: &HashSet<VID>,
+) -> (usize, usize) {
+    let mut term = 0;
+    let mut new = 0;
+    for neighbour in v.neighbours().iter() {
+        if core.contains_key(&neighbour.vertex) {
+            continue;
+        } else if terminal.contains(&neighbour.vertex) {
+            term += 1;
+        } else {
+            new += 1;
+        }
+    }
+    (term, new)
+}
+
+fn search<G1, G2, NM, EM>(
+    state: &mut State<G1, G2>,
+    remaining: usize,
+    node_match: &NM,
+    edge_match: &EM,
+) -> bool
+where
+    G1: GraphViewOps,
+    G2: GraphViewOps,
+    NM: Fn(&VertexView<G1>, &VertexView<G2>) -> bool,
+    EM: Fn(&EdgeView<G1, G1>, &EdgeView<G2, G2>) -> bool,
+{
+    if remaining == 0 {
+        return true;
+    }
+
+    let Some(n) = next_candidate_g1(state) else {
+        return false;
+    };
+
+    let candidates: Vec<VID> = state
+        .g2
+        .vertices()
+        .iter()
+        .map(|v| v.vertex)
+        .filter(|v| !state.core_2.contains_key(v))
+        .collect();
+
+    for m in candidates {
+        if !feasible(state, n, m, node_match, edge_match) {
+            continue;
+        }
+
+        state.core_1.insert(n, m);
+        state.core_2.insert(m, n);
+        let added_1 = update_terminal(state.g1, n, &state.core_1, &mut state.terminal_1);
+        let added_2 = update_terminal(state.g2, m, &state.core_2, &mut state.terminal_2);
+
+        if search(state, remaining - 1, node_match, edge_match) {
+            return true;
+        }
+
+        state.core_1.remove(&n);
+        state.core_2.remove(&m);
+        for v in added_1 {
+            state.terminal_1.remove(&v);
+        }
+        for v in added_2 {
+            state.terminal_2.remove(&v);
+        }
+    }
+
+    false
+}
+
+/// Adds the newly-mapped vertex's unmapped neighbours to the terminal set, returning the ones
+/// that were newly inserted so the caller can undo this on backtrack.
+fn update_terminal<G: GraphViewOps>(
+    graph: &G,
+    vid: VID,
+    core: &HashMap<VID, VID>,
+    terminal: &mut HashSet<VID>,
+) -> Vec<VID> {
+    let mut added = Vec::new();
+    for neighbour in vertex_at(graph, vid).neighbours().iter() {
+        if !core.contains_key(&neighbour.vertex) && terminal.insert(neighbour.vertex) {
+            added.push(neighbour.vertex);
+        }
+    }
+    added
+}
+
+#[cfg(test)]
+mod test {
+    use super::is_isomorphic_matching;
+    use crate::{
+        core::Prop,
+        db::{api::mutation::AdditionOps, graph::graph::Graph},
+        prelude::*,
+    };
+
+    // Build a 3-node directed path a -> b -> c. `reversed` controls whether the second edge is
+    // inserted before the first, which shifts the resulting VID assignment (VIDs are handed out
+    // in insertion order) without changing the graph's shape. Using `reversed = true` for one
+    // side and `false` for the other means the correct isomorphism mapping is never the identity
+    // on VIDs, so a buggy `feasible` comparing a g1 VID directly against a g2 VID can't
+    // accidentally "match" by numeric coincidence.
+    fn path_graph(edges: [(&str, &str, f64); 2], reversed: bool) -> Graph {
+        let g = Graph::new();
+        let order: [usize; 2] = if reversed { [1, 0] } else { [0, 1] };
+        for i in order {
+            let (src, dst, w) = edges[i];
+            g.add_edge(0, src, dst, [("weight".to_string(), Prop::F64(w))], None)
+                .unwrap();
+        }
+        g
+    }
+
+    fn weight_edge_match<G1: GraphViewOps, G2: GraphViewOps>(
+        e1: &crate::db::graph::edge::EdgeView<G1, G1>,
+        e2: &crate::db::graph::edge::EdgeView<G2, G2>,
+    ) -> bool {
+        let w1 = e1.properties().get("weight").and_then(|v| v.into_f64());
+        let w2 = e2.properties().get("weight").and_then(|v| v.into_f64());
+        w1 == w2
+    }
+
+    #[test]
+    fn edge_match_rejects_mismatched_weights_on_isomorphic_structure() {
+        let g1 = path_graph([("1", "2", 1.0), ("2", "3", 2.0)], false);
+        let g2 = path_graph([("10", "20", 1.0), ("20", "30", 99.0)], true);
+
+        assert!(!is_isomorphic_matching(
+            &g1,
+            &g2,
+            |_, _| true,
+            weight_edge_match,
+        ));
+    }
+
+    #[test]
+    fn edge_match_accepts_matching_weights_on_isomorphic_structure() {
+        let g1 = path_graph([("1", "2", 1.0), ("2", "3", 2.0)], false);
+        let g2 = path_graph([("10", "20", 1.0), ("20", "30", 2.0)], true);
+
+        assert!(is_isomorphic_matching(
+            &g1,
+            &g2,
+            |_, _| true,
+            weight_edge_match,
+        ));
+    }
+}
@@ -0,0 +1,183 @@
+//! # Dense Adjacency Matrix
+//!
+//! A one-shot snapshot of edge presence for a (possibly windowed/layered) graph view, modeled on
+//! petgraph's `GetAdjacencyMatrix`. Building the snapshot once and then calling
+//! [`AdjacencyMatrix::is_adjacent`] is much cheaper than repeatedly walking the layered/filtered
+//! edge dispatch when an algorithm (isomorphism checks, triangle counting, motif detection) needs
+//! many edge-presence queries over the same view. Presence is packed one bit per `(src, dst)`
+//! pair, `ceil(n/64)` `u64` words per row, where `n` is the number of nodes in the view.
+//!
+//! [`AdjacencyMatrix::build_from_ops`] is the primitive requested against [`GraphOps`] directly —
+//! it only calls `node_refs`/`edge_refs` with an explicit `LayerIds`/`EdgeFilter`, so it pays the
+//! layered/filtered dispatch exactly once no matter how many `is_adjacent` queries follow.
+//! [`AdjacencyMatrix::build`]/[`AdjacencyMatrixOps`] are the view-layer convenience built on top,
+//! for the common case of "snapshot everything this view currently shows".
+use crate::{
+    core::entities::{LayerIds, VID},
+    db::api::view::{
+        internal::{EdgeFilter, GraphOps},
+        StaticGraphViewOps,
+    },
+    prelude::{EdgeViewOps, GraphViewOps, NodeViewOps},
+};
+use std::collections::HashMap;
+
+/// A dense, bit-packed `n x n` adjacency snapshot of a graph view.
+pub struct AdjacencyMatrix {
+    id_to_idx: HashMap<VID, usize>,
+    n: usize,
+    words_per_row: usize,
+    bits: Vec<u64>,
+}
+
+impl AdjacencyMatrix {
+    /// Builds the snapshot straight off [`GraphOps`], as requested: allocates the bit-packed
+    /// buffer off `node_refs(layers, filter)`, then sets one bit per edge by draining
+    /// `edge_refs(layers, filter)` exactly once.
+    pub fn build_from_ops<'graph, G: GraphOps<'graph> + ?Sized>(
+        graph: &G,
+        layers: LayerIds,
+        filter: Option<&EdgeFilter>,
+    ) -> Self {
+        let id_to_idx: HashMap<VID, usize> = graph
+            .node_refs(layers.clone(), filter)
+            .enumerate()
+            .map(|(i, v)| (v, i))
+            .collect();
+        let n = id_to_idx.len();
+        let words_per_row = n.div_ceil(64).max(1);
+        let mut bits = vec![0u64; words_per_row * n.max(1)];
+
+        for edge in graph.edge_refs(layers, filter) {
+            let src = id_to_idx[&edge.src()];
+            let dst = id_to_idx[&edge.dst()];
+            let bit = src * words_per_row * 64 + dst;
+            bits[bit / 64] |= 1 << (bit % 64);
+        }
+
+        Self {
+            id_to_idx,
+            n,
+            words_per_row,
+            bits,
+        }
+    }
+
+    /// Builds the snapshot for everything currently visible through `graph`, the common
+    /// view-layer entry point: `g.window(a, b).adjacency_matrix()`.
+    pub fn build<G: StaticGraphViewOps>(graph: &G) -> Self {
+        let id_to_idx: HashMap<VID, usize> = graph
+            .nodes()
+            .into_iter()
+            .map(|n| n.vertex)
+            .enumerate()
+            .map(|(i, v)| (v, i))
+            .collect();
+        let n = id_to_idx.len();
+        let words_per_row = n.div_ceil(64).max(1);
+        let mut bits = vec![0u64; words_per_row * n.max(1)];
+
+        for edge in graph.edges() {
+            let src = id_to_idx[&edge.src().vertex];
+            let dst = id_to_idx[&edge.dst().vertex];
+            let bit = src * words_per_row * 64 + dst;
+            bits[bit / 64] |= 1 << (bit % 64);
+        }
+
+        Self {
+            id_to_idx,
+            n,
+            words_per_row,
+            bits,
+        }
+    }
+
+    /// The number of nodes captured in this snapshot.
+    pub fn len(&self) -> usize {
+        self.n
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.n == 0
+    }
+
+    /// Whether there is an edge from `src` to `dst` in O(1), or `false` if either id was not
+    /// present in the view this snapshot was built from.
+    pub fn is_adjacent(&self, src: VID, dst: VID) -> bool {
+        let Some(&si) = self.id_to_idx.get(&src) else {
+            return false;
+        };
+        let Some(&di) = self.id_to_idx.get(&dst) else {
+            return false;
+        };
+        let bit = si * self.words_per_row * 64 + di;
+        self.bits[bit / 64] & (1 << (bit % 64)) != 0
+    }
+}
+
+/// Fluent access to [`AdjacencyMatrix::build`] directly on a graph view, e.g.
+/// `g.window(a, b).adjacency_matrix().is_adjacent(u, v)`.
+pub trait AdjacencyMatrixOps<G: StaticGraphViewOps> {
+    fn adjacency_matrix(&self) -> AdjacencyMatrix;
+}
+
+impl<G: StaticGraphViewOps> AdjacencyMatrixOps<G> for G {
+    fn adjacency_matrix(&self) -> AdjacencyMatrix {
+        AdjacencyMatrix::build(self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::AdjacencyMatrixOps;
+    use crate::{
+        db::{api::mutation::AdditionOps, graph::graph::Graph},
+        prelude::*,
+    };
+
+    fn directed_triangle() -> Graph {
+        let g = Graph::new();
+        g.add_edge(0, "a", "b", NO_PROPS, None).unwrap();
+        g.add_edge(0, "b", "c", NO_PROPS, None).unwrap();
+        g.add_edge(0, "c", "a", NO_PROPS, None).unwrap();
+        g
+    }
+
+    #[test]
+    fn reports_adjacency_only_in_the_direction_the_edge_was_added() {
+        let g = directed_triangle();
+        let matrix = g.adjacency_matrix();
+        let a = g.node("a").unwrap().vertex;
+        let b = g.node("b").unwrap().vertex;
+        let c = g.node("c").unwrap().vertex;
+
+        assert!(matrix.is_adjacent(a, b));
+        assert!(!matrix.is_adjacent(b, a));
+        assert!(matrix.is_adjacent(b, c));
+        assert!(matrix.is_adjacent(c, a));
+        assert!(!matrix.is_adjacent(a, c));
+    }
+
+    #[test]
+    fn unknown_ids_are_never_adjacent() {
+        let g = directed_triangle();
+        let matrix = g.adjacency_matrix();
+
+        // `g` has three nodes (VIDs 0..3), so VID 999 is absent from its snapshot.
+        let unknown = crate::core::entities::VID::from(999);
+
+        assert!(!matrix.is_adjacent(unknown, unknown));
+    }
+
+    #[test]
+    fn len_tracks_the_node_count_and_empty_graph_has_no_adjacency() {
+        let g = directed_triangle();
+        assert_eq!(g.adjacency_matrix().len(), 3);
+        assert!(!g.adjacency_matrix().is_empty());
+
+        let empty = Graph::new();
+        let matrix = empty.adjacency_matrix();
+        assert!(matrix.is_empty());
+        assert_eq!(matrix.len(), 0);
+    }
+}
@@ -0,0 +1,195 @@
+//! # Bicolor Run Collection
+//!
+//! Groups nodes of an acyclic graph into maximal runs connected by edges of two distinct
+//! "colors", which is useful for pattern-extraction passes such as collapsing chains of
+//! same-type interactions.
+use crate::{
+    db::{api::view::StaticGraphViewOps, graph::node::NodeView},
+    prelude::{EdgeViewOps, GraphViewOps, NodeViewOps},
+};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Returns the nodes of `graph` in topological order, assuming the graph is acyclic.
+/// Uses Kahn's algorithm over in-degree counts.
+fn topological_order<G: StaticGraphViewOps>(graph: &G) -> Vec<NodeView<G>> {
+    let mut in_degree: HashMap<u64, usize> = HashMap::new();
+    for node in graph.nodes() {
+        in_degree.insert(node.id(), node.in_degree());
+    }
+
+    let mut queue: VecDeque<u64> = in_degree
+        .iter()
+        .filter(|(_, &d)| d == 0)
+        .map(|(&id, _)| id)
+        .collect();
+
+    let mut order = Vec::with_capacity(in_degree.len());
+    while let Some(id) = queue.pop_front() {
+        let node = graph.node(id).expect("node must exist");
+        order.push(node.clone());
+        for out_neighbour in node.out_neighbours() {
+            let nid = out_neighbour.id();
+            if let Some(d) = in_degree.get_mut(&nid) {
+                *d -= 1;
+                if *d == 0 {
+                    queue.push_back(nid);
+                }
+            }
+        }
+    }
+    order
+}
+
+/// Walks `graph` in topological order and groups nodes into maximal runs connected by edges of
+/// two distinct "colors".
+///
+/// # Arguments
+/// - `graph`: An acyclic graph view.
+/// - `node_match_fn`: Selects which nodes are eligible to belong to a run.
+/// - `edge_color_fn`: Maps each edge to `Some(color)`, or `None` to ignore the edge entirely.
+///
+/// # Returns
+/// A `Vec<Vec<NodeView<G>>>` of the maximal runs discovered, in the order they were closed.
+pub fn collect_bicolor_runs<G, NodeMatch, EdgeColor>(
+    graph: &G,
+    node_match_fn: NodeMatch,
+    edge_color_fn: EdgeColor,
+) -> Vec<Vec<NodeView<G>>>
+where
+    G: StaticGraphViewOps,
+    NodeMatch: Fn(&NodeView<G>) -> bool,
+    EdgeColor: Fn(&crate::db::graph::edge::EdgeView<G, G>) -> Option<usize>,
+{
+    // Colour -> index into `runs` of the run currently open on that colour.
+    let mut open_runs: HashMap<usize, usize> = HashMap::new();
+    let mut runs: Vec<Option<Vec<NodeView<G>>>> = Vec::new();
+    let mut finished: Vec<Vec<NodeView<G>>> = Vec::new();
+
+    for node in topological_order(graph) {
+        if !node_match_fn(&node) {
+            continue;
+        }
+
+        // Find an in-edge whose colour has a run pending, preferring the first match.
+        let incoming_color = node
+            .in_edges()
+            .into_iter()
+            .find_map(|e| edge_color_fn(&e).filter(|c| open_runs.contains_key(c)));
+
+        let run_idx = match incoming_color {
+            Some(c_in) => {
+                let idx = open_runs.remove(&c_in).unwrap();
+                runs[idx].as_mut().unwrap().push(node.clone());
+                idx
+            }
+            None => {
+                runs.push(Some(vec![node.clone()]));
+                runs.len() - 1
+            }
+        };
+
+        // Register the run under the colour of its outgoing edge, if any; otherwise it can
+        // never be extended again, so flush it immediately.
+        let outgoing_color = node.out_edges().into_iter().find_map(|e| edge_color_fn(&e));
+        match outgoing_color {
+            Some(c_out) => {
+                open_runs.insert(c_out, run_idx);
+            }
+            None => {
+                if let Some(run) = runs[run_idx].take() {
+                    finished.push(run);
+                }
+            }
+        }
+    }
+
+    // Flush any runs still open at the end (they can no longer be extended).
+    let leftover: HashSet<usize> = open_runs.values().copied().collect();
+    for idx in leftover {
+        if let Some(run) = runs[idx].take() {
+            finished.push(run);
+        }
+    }
+
+    finished
+}
+
+#[cfg(test)]
+mod test {
+    use super::collect_bicolor_runs;
+    use crate::{
+        core::Prop,
+        db::{api::mutation::AdditionOps, graph::graph::Graph},
+        prelude::*,
+    };
+    use std::collections::HashSet;
+
+    fn edge_color<G: GraphViewOps>(edge: &crate::db::graph::edge::EdgeView<G, G>) -> Option<usize> {
+        edge.properties()
+            .get("color")
+            .and_then(|v| v.into_u64())
+            .map(|c| c as usize)
+    }
+
+    fn add_colored_edge(g: &Graph, src: &str, dst: &str, color: u64) {
+        g.add_edge(0, src, dst, [("color".to_string(), Prop::U64(color))], None)
+            .unwrap();
+    }
+
+    fn run_ids(runs: Vec<Vec<crate::db::graph::node::NodeView<Graph>>>) -> HashSet<Vec<u64>> {
+        runs.into_iter()
+            .map(|run| {
+                let mut ids: Vec<u64> = run.into_iter().map(|n| n.id()).collect();
+                ids.sort_unstable();
+                ids
+            })
+            .collect()
+    }
+
+    #[test]
+    fn two_disjoint_color_chains_collect_as_two_separate_runs() {
+        let g = Graph::new();
+        add_colored_edge(&g, "p1", "p2", 0);
+        add_colored_edge(&g, "p2", "p3", 1);
+        add_colored_edge(&g, "q1", "q2", 2);
+        add_colored_edge(&g, "q2", "q3", 3);
+
+        let runs = collect_bicolor_runs(&g, |_| true, edge_color);
+        let p1 = g.node("p1").unwrap().id();
+        let p2 = g.node("p2").unwrap().id();
+        let p3 = g.node("p3").unwrap().id();
+        let q1 = g.node("q1").unwrap().id();
+        let q2 = g.node("q2").unwrap().id();
+        let q3 = g.node("q3").unwrap().id();
+
+        let mut expected = HashSet::new();
+        expected.insert({
+            let mut v = vec![p1, p2, p3];
+            v.sort_unstable();
+            v
+        });
+        expected.insert({
+            let mut v = vec![q1, q2, q3];
+            v.sort_unstable();
+            v
+        });
+        assert_eq!(run_ids(runs), expected);
+    }
+
+    #[test]
+    fn excluding_a_node_breaks_the_run_at_that_point() {
+        let g = Graph::new();
+        add_colored_edge(&g, "p1", "p2", 0);
+        add_colored_edge(&g, "p2", "p3", 1);
+
+        let p2_id = g.node("p2").unwrap().id();
+        let runs = collect_bicolor_runs(&g, move |n| n.id() != p2_id, edge_color);
+
+        let p1 = g.node("p1").unwrap().id();
+        let p3 = g.node("p3").unwrap().id();
+        let mut expected = HashSet::new();
+        expected.insert(vec![p1]);
+        expected.insert(vec![p3]);
+        assert_eq!(run_ids(runs), expected);
+    }
+}
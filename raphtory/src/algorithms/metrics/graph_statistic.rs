@@ -0,0 +1,318 @@
+//! # Cached Graph Statistics
+//!
+//! A precomputed [`GraphStatistic`] summary that accelerates the counting-heavy methods on a
+//! read-heavy analytical view — node/edge totals and per-node degree/neighbours — by building a
+//! dense successor array once rather than re-walking the live edge iteration on every call.
+//! Low out-degree nodes (the common case) get an O(1) direct lookup into a flat `Vec`; high-
+//! degree hubs, where a dense per-node slot would be wasteful, fall back to a `HashMap` of
+//! adjacency lists.
+//!
+//! The cache is **not** automatically kept in sync with the underlying view: [`StatisticsView`]
+//! cheaply compares the view's current node/edge totals against the snapshot on every
+//! `degree`/`neighbours` call, and falls back to a live walk if either has changed, but it does
+//! not re-cache the result, and it cannot detect a same-count mutation (e.g. an edge rewired
+//! between two nodes without changing the total). Call [`StatisticsView::refresh`] after mutating
+//! the graph to get O(1) lookups back; when in doubt, call it before relying on cached results.
+//!
+//! [`GraphStatistic::build_from_ops`] is built directly on [`GraphOps`] — `node_refs`/`degree`/
+//! `neighbours`, keyed on [`VID`] with an explicit `LayerIds`/`EdgeFilter` — since the cache is
+//! purely structural and has no temporal data to read. [`GraphStatistic::build`] and
+//! [`StatisticsView`] are the thin `StaticGraphViewOps` wrapper that supplies `layers`/`filter`
+//! from the view's current selection, same as [`earliest_arrival_times_from_ops`].
+//!
+//! [`earliest_arrival_times_from_ops`]: crate::algorithms::pathing::earliest_arrival::earliest_arrival_times_from_ops
+use crate::{
+    core::entities::{LayerIds, VID},
+    db::api::view::{
+        internal::{EdgeFilter, GraphOps},
+        StaticGraphViewOps,
+    },
+    prelude::GraphViewOps,
+};
+use std::collections::HashMap;
+
+/// Above this out-degree, a node's successors are kept in the [`GraphStatistic::fallback`] map
+/// instead of a per-node slot in the dense array.
+const DENSE_DEGREE_CAP: usize = 1;
+
+/// A one-shot summary of a graph view's size and per-node degree, cheap to query repeatedly.
+pub struct GraphStatistic {
+    node_count: usize,
+    edge_count: usize,
+    max_degree: usize,
+    avg_degree: f64,
+    /// `true` once the edge density (`edge_count / (node_count * (node_count - 1))`) crosses
+    /// [`Self::DENSITY_THRESHOLD`], at which point a dense adjacency representation earns its
+    /// memory cost.
+    is_dense: bool,
+    /// One slot per node (`None` when the node's out-neighbours are in [`Self::fallback`]
+    /// instead, because its out-degree exceeds [`DENSE_DEGREE_CAP`]).
+    dense: Vec<Option<VID>>,
+    fallback: HashMap<VID, Vec<VID>>,
+    id_to_idx: HashMap<VID, usize>,
+}
+
+impl GraphStatistic {
+    const DENSITY_THRESHOLD: f64 = 0.1;
+
+    /// Builds the cache in one pass over `graph`'s nodes, using only [`GraphOps`] primitives:
+    /// `node_refs` to enumerate nodes and `neighbours` to read each one's out-neighbours.
+    pub fn build_from_ops<'graph, G: GraphOps<'graph> + ?Sized>(
+        graph: &G,
+        layers: LayerIds,
+        filter: Option<&EdgeFilter>,
+    ) -> Self {
+        let nodes: Vec<VID> = graph.node_refs(layers.clone(), filter).collect();
+        let node_count = nodes.len();
+        let id_to_idx: HashMap<VID, usize> = nodes
+            .iter()
+            .copied()
+            .enumerate()
+            .map(|(i, id)| (id, i))
+            .collect();
+
+        let mut dense = vec![None; node_count];
+        let mut fallback = HashMap::new();
+        let mut edge_count = 0usize;
+        let mut max_degree = 0usize;
+
+        for &node in &nodes {
+            let out_neighbours: Vec<VID> = graph
+                .neighbours(node, crate::core::Direction::OUT, layers.clone(), filter)
+                .collect();
+            edge_count += out_neighbours.len();
+            max_degree = max_degree.max(out_neighbours.len());
+            let idx = id_to_idx[&node];
+            if out_neighbours.len() <= DENSE_DEGREE_CAP {
+                dense[idx] = out_neighbours.first().copied();
+            } else {
+                fallback.insert(node, out_neighbours);
+            }
+        }
+
+        let avg_degree = if node_count == 0 {
+            0.0
+        } else {
+            edge_count as f64 / node_count as f64
+        };
+        let max_pairs = (node_count as f64) * (node_count.saturating_sub(1) as f64);
+        let is_dense = max_pairs > 0.0 && edge_count as f64 / max_pairs > Self::DENSITY_THRESHOLD;
+
+        Self {
+            node_count,
+            edge_count,
+            max_degree,
+            avg_degree,
+            is_dense,
+            dense,
+            fallback,
+            id_to_idx,
+        }
+    }
+
+    /// The view-layer wrapper: supplies `layers`/`filter` from `graph`'s current layer/window
+    /// selection and delegates to [`Self::build_from_ops`].
+    pub fn build<G: StaticGraphViewOps>(graph: &G) -> Self {
+        Self::build_from_ops(graph, graph.layer_ids(), graph.edge_filter())
+    }
+
+    pub fn node_count(&self) -> usize {
+        self.node_count
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.edge_count
+    }
+
+    pub fn max_degree(&self) -> usize {
+        self.max_degree
+    }
+
+    pub fn avg_degree(&self) -> f64 {
+        self.avg_degree
+    }
+
+    pub fn is_dense(&self) -> bool {
+        self.is_dense
+    }
+
+    /// The cached out-degree of `node`, or `None` if it is not in this snapshot.
+    pub fn degree(&self, node: VID) -> Option<usize> {
+        let &idx = self.id_to_idx.get(&node)?;
+        Some(match &self.dense[idx] {
+            Some(_) => 1,
+            None => self.fallback.get(&node).map(|v| v.len()).unwrap_or(0),
+        })
+    }
+
+    /// The cached out-neighbours of `node`, or `None` if it is not in this snapshot.
+    pub fn neighbours(&self, node: VID) -> Option<Vec<VID>> {
+        let &idx = self.id_to_idx.get(&node)?;
+        Some(match &self.dense[idx] {
+            Some(only) => vec![*only],
+            None => self.fallback.get(&node).cloned().unwrap_or_default(),
+        })
+    }
+}
+
+/// A graph view paired with a [`GraphStatistic`] cache, consulted first by `degree`/`neighbours`
+/// and only falling back to a live `out_neighbours()` walk on a cache miss, or on any call once
+/// the view's node/edge totals have drifted from the snapshot (see the module docs for what this
+/// staleness check does and does not catch).
+pub struct StatisticsView<G> {
+    graph: G,
+    stats: GraphStatistic,
+}
+
+impl<G: StaticGraphViewOps> StatisticsView<G> {
+    pub fn nodes_len(&self) -> usize {
+        self.stats.node_count()
+    }
+
+    pub fn edges_len(&self) -> usize {
+        self.stats.edge_count()
+    }
+
+    pub fn degree(&self, node: VID) -> usize {
+        let cached = if self.live_fallback_needed() {
+            None
+        } else {
+            self.stats.degree(node)
+        };
+        match cached {
+            Some(d) => d,
+            None => self.graph.degree(
+                node,
+                crate::core::Direction::OUT,
+                &self.graph.layer_ids(),
+                self.graph.edge_filter(),
+            ),
+        }
+    }
+
+    pub fn neighbours(&self, node: VID) -> Vec<VID> {
+        let cached = if self.live_fallback_needed() {
+            None
+        } else {
+            self.stats.neighbours(node)
+        };
+        match cached {
+            Some(n) => n,
+            None => self
+                .graph
+                .neighbours(
+                    node,
+                    crate::core::Direction::OUT,
+                    self.graph.layer_ids(),
+                    self.graph.edge_filter(),
+                )
+                .collect(),
+        }
+    }
+
+    /// Whether the view's current node/edge totals have drifted from the cached snapshot,
+    /// meaning a mutation has happened since `build`/`refresh` and the whole cache should be
+    /// treated as stale (as opposed to the per-node "not in the snapshot" cache miss, which is
+    /// still safe to serve from the snapshot).
+    fn live_fallback_needed(&self) -> bool {
+        self.graph.nodes_len(self.graph.layer_ids(), self.graph.edge_filter())
+            != self.stats.node_count()
+            || self.graph.edges_len(self.graph.layer_ids(), self.graph.edge_filter())
+                != self.stats.edge_count()
+    }
+
+    pub fn statistics(&self) -> &GraphStatistic {
+        &self.stats
+    }
+
+    /// Rebuilds the cache from the current state of the underlying view, invalidating anything
+    /// computed before a mutation.
+    pub fn refresh(&mut self) {
+        self.stats = GraphStatistic::build(&self.graph);
+    }
+}
+
+/// Fluent access to [`StatisticsView`] directly on a graph view, e.g.
+/// `g.with_statistics().degree(v)`.
+pub trait WithStatistics: StaticGraphViewOps + Sized {
+    fn with_statistics(&self) -> StatisticsView<Self>;
+}
+
+impl<G: StaticGraphViewOps> WithStatistics for G {
+    fn with_statistics(&self) -> StatisticsView<Self> {
+        StatisticsView {
+            graph: self.clone(),
+            stats: GraphStatistic::build(self),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{GraphStatistic, WithStatistics};
+    use crate::{
+        core::entities::VID,
+        db::{api::mutation::AdditionOps, graph::graph::Graph},
+        prelude::*,
+    };
+    use std::collections::HashSet;
+
+    // a -> b, a -> c, b -> c: a has out-degree 2 (routed through the fallback map), b has
+    // out-degree 1 (fits the dense slot), c has out-degree 0.
+    fn fan_out_graph() -> Graph {
+        let g = Graph::new();
+        g.add_edge(0, "a", "b", NO_PROPS, None).unwrap();
+        g.add_edge(0, "a", "c", NO_PROPS, None).unwrap();
+        g.add_edge(0, "b", "c", NO_PROPS, None).unwrap();
+        g
+    }
+
+    #[test]
+    fn caches_degree_and_neighbours_for_both_the_dense_and_fallback_paths() {
+        let g = fan_out_graph();
+        let stats = GraphStatistic::build(&g);
+        let a = g.node("a").unwrap().vertex;
+        let b = g.node("b").unwrap().vertex;
+        let c = g.node("c").unwrap().vertex;
+
+        assert_eq!(stats.node_count(), 3);
+        assert_eq!(stats.edge_count(), 3);
+        assert_eq!(stats.max_degree(), 2);
+        assert_eq!(stats.avg_degree(), 1.0);
+
+        assert_eq!(stats.degree(a), Some(2));
+        assert_eq!(
+            stats.neighbours(a).unwrap().into_iter().collect::<HashSet<_>>(),
+            HashSet::from([b, c])
+        );
+        assert_eq!(stats.degree(b), Some(1));
+        assert_eq!(stats.neighbours(b), Some(vec![c]));
+        assert_eq!(stats.degree(c), Some(0));
+        assert_eq!(stats.neighbours(c), Some(Vec::new()));
+
+        assert_eq!(stats.degree(VID::from(999)), None);
+    }
+
+    #[test]
+    fn a_stale_snapshot_falls_back_to_a_live_walk_until_refreshed() {
+        let g = fan_out_graph();
+        let mut view = g.with_statistics();
+        let a = g.node("a").unwrap().vertex;
+        let b = g.node("b").unwrap().vertex;
+
+        assert_eq!(view.degree(b), 1);
+
+        // Mutating after the snapshot was built changes the edge total, so degree/neighbours
+        // must notice the drift and answer from a live walk instead of the stale cache.
+        g.add_edge(0, "b", "a", NO_PROPS, None).unwrap();
+        assert_eq!(view.degree(b), 2);
+        assert_eq!(
+            view.neighbours(b).into_iter().collect::<HashSet<_>>(),
+            HashSet::from([a, g.node("c").unwrap().vertex])
+        );
+
+        view.refresh();
+        assert_eq!(view.statistics().edge_count(), 4);
+        assert_eq!(view.degree(b), 2);
+    }
+}
@@ -0,0 +1,471 @@
+//! # Max Flow / Min Cost Flow
+//!
+//! This algorithm computes the maximum amount of flow that can be pushed between a source and
+//! a sink node, treating a named edge property as the capacity of each edge. A min-cost variant
+//! additionally takes a cost property and finds the cheapest way to realize the maximum flow.
+use crate::{
+    core::{entities::VID, utils::errors::GraphError},
+    db::api::view::StaticGraphViewOps,
+    prelude::{EdgeViewOps, GraphViewOps, NodeViewOps, PropUnwrap},
+};
+use std::collections::{HashMap, VecDeque};
+
+/// The result of a max-flow (or min-cost max-flow) computation.
+///
+/// Unlike the per-node [`AlgorithmResult`](crate::algorithms::algorithm_result::AlgorithmResult)
+/// used elsewhere in `algorithms::metrics`, flow is naturally a per-edge quantity, so this keeps
+/// its own small result type keyed by `(src, dst)` node ids rather than forcing edges through a
+/// node-keyed map.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlowResult {
+    /// The total flow pushed from source to sink.
+    pub total_flow: f64,
+    /// Total cost of the flow (only meaningful for [`min_cost_flow`]).
+    pub total_cost: f64,
+    /// The realized flow along each directed edge that carried flow.
+    pub edge_flows: HashMap<(u64, u64), f64>,
+}
+
+/// Builds a residual adjacency map `u -> [(v, capacity)]` from the named capacity property,
+/// summing capacities when parallel edges exist between the same pair of nodes.
+fn residual_graph<'graph, G: GraphViewOps<'graph>>(
+    graph: &G,
+    capacity_prop: &str,
+) -> (HashMap<VID, HashMap<VID, f64>>, Vec<VID>) {
+    let mut residual: HashMap<VID, HashMap<VID, f64>> = HashMap::new();
+    let mut nodes = Vec::new();
+
+    for node in graph.nodes() {
+        nodes.push(node.node);
+        residual.entry(node.node).or_default();
+    }
+
+    for edge in graph.edges() {
+        let cap = edge
+            .properties()
+            .temporal()
+            .get(capacity_prop)
+            .and_then(|v| v.latest())
+            .and_then(|v| v.into_f64())
+            .unwrap_or(0.0);
+        if cap <= 0.0 {
+            continue;
+        }
+        let src = edge.src().node;
+        let dst = edge.dst().node;
+        *residual.entry(src).or_default().entry(dst).or_insert(0.0) += cap;
+        residual.entry(dst).or_default().entry(src).or_insert(0.0);
+    }
+
+    (residual, nodes)
+}
+
+/// Finds an augmenting path from `source` to `sink` via BFS through edges with positive
+/// residual capacity, returning the path (as a list of nodes) if one exists.
+fn bfs_augmenting_path(
+    residual: &HashMap<VID, HashMap<VID, f64>>,
+    source: VID,
+    sink: VID,
+) -> Option<Vec<VID>> {
+    let mut visited = HashMap::new();
+    visited.insert(source, source);
+    let mut queue = VecDeque::new();
+    queue.push_back(source);
+
+    while let Some(u) = queue.pop_front() {
+        if u == sink {
+            let mut path = vec![sink];
+            let mut cur = sink;
+            while cur != source {
+                cur = visited[&cur];
+                path.push(cur);
+            }
+            path.reverse();
+            return Some(path);
+        }
+        if let Some(neighbours) = residual.get(&u) {
+            for (&v, &cap) in neighbours {
+                if cap > 0.0 && !visited.contains_key(&v) {
+                    visited.insert(v, u);
+                    queue.push_back(v);
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Computes the maximum flow between `source` and `sink`, treating `capacity_prop` as the
+/// per-edge capacity, using the Edmonds–Karp algorithm (BFS-based augmenting paths).
+///
+/// # Arguments
+/// - `graph`: The graph to compute the flow over.
+/// - `source`: The id of the source node.
+/// - `sink`: The id of the sink node.
+/// - `capacity_prop`: The name of the edge property to use as capacity.
+///
+/// # Returns
+/// `Ok(None)` if `source` or `sink` does not exist in this view, otherwise a [`FlowResult`] with
+/// the total flow and the realized per-edge flow assignment.
+pub fn max_flow<G: StaticGraphViewOps>(
+    graph: &G,
+    source: u64,
+    sink: u64,
+    capacity_prop: &str,
+) -> Result<Option<FlowResult>, GraphError> {
+    let Some(source) = graph.node(source).map(|v| v.node) else {
+        return Ok(None);
+    };
+    let Some(sink) = graph.node(sink).map(|v| v.node) else {
+        return Ok(None);
+    };
+    if source == sink {
+        return Ok(Some(FlowResult {
+            total_flow: 0.0,
+            total_cost: 0.0,
+            edge_flows: HashMap::new(),
+        }));
+    }
+
+    let (mut residual, _) = residual_graph(graph, capacity_prop);
+    let mut total_flow = 0.0;
+
+    while let Some(path) = bfs_augmenting_path(&residual, source, sink) {
+        let bottleneck = path
+            .windows(2)
+            .map(|pair| residual[&pair[0]][&pair[1]])
+            .fold(f64::INFINITY, f64::min);
+
+        for pair in path.windows(2) {
+            let (u, v) = (pair[0], pair[1]);
+            *residual.get_mut(&u).unwrap().get_mut(&v).unwrap() -= bottleneck;
+            *residual.get_mut(&v).unwrap().get_mut(&u).unwrap() += bottleneck;
+        }
+        total_flow += bottleneck;
+    }
+
+    let edge_flows = flows_from_residual(graph, capacity_prop, &residual);
+
+    Ok(Some(FlowResult {
+        total_flow,
+        total_cost: 0.0,
+        edge_flows,
+    }))
+}
+
+/// Recovers the realized per-edge flow by comparing the original capacity with what remains
+/// in the residual graph after running the augmenting-path search.
+fn flows_from_residual<'graph, G: GraphViewOps<'graph>>(
+    graph: &G,
+    capacity_prop: &str,
+    residual: &HashMap<VID, HashMap<VID, f64>>,
+) -> HashMap<(u64, u64), f64> {
+    let mut edge_flows = HashMap::new();
+    for edge in graph.edges() {
+        let cap = edge
+            .properties()
+            .temporal()
+            .get(capacity_prop)
+            .and_then(|v| v.latest())
+            .and_then(|v| v.into_f64())
+            .unwrap_or(0.0);
+        if cap <= 0.0 {
+            continue;
+        }
+        let src = edge.src();
+        let dst = edge.dst();
+        let remaining = residual
+            .get(&src.node)
+            .and_then(|m| m.get(&dst.node))
+            .copied()
+            .unwrap_or(cap);
+        let flow = (cap - remaining).max(0.0);
+        if flow > 0.0 {
+            *edge_flows.entry((src.id(), dst.id())).or_insert(0.0) += flow;
+        }
+    }
+    edge_flows
+}
+
+/// Finds the shortest (by cost) augmenting path from `source` to `sink` using SPFA
+/// (Bellman–Ford with a FIFO worklist), which tolerates the negative-cost reverse edges that
+/// appear once flow has been pushed along the forward direction.
+fn spfa_augmenting_path(
+    residual: &HashMap<VID, HashMap<VID, f64>>,
+    cost: &HashMap<(VID, VID), f64>,
+    source: VID,
+    sink: VID,
+) -> Option<Vec<VID>> {
+    let mut dist: HashMap<VID, f64> = HashMap::new();
+    let mut prev: HashMap<VID, VID> = HashMap::new();
+    let mut in_queue: HashMap<VID, bool> = HashMap::new();
+    dist.insert(source, 0.0);
+    let mut queue = VecDeque::new();
+    queue.push_back(source);
+    in_queue.insert(source, true);
+
+    while let Some(u) = queue.pop_front() {
+        in_queue.insert(u, false);
+        let Some(neighbours) = residual.get(&u) else {
+            continue;
+        };
+        for (&v, &cap) in neighbours {
+            if cap <= 0.0 {
+                continue;
+            }
+            let edge_cost = *cost.get(&(u, v)).unwrap_or(&0.0);
+            let new_dist = dist[&u] + edge_cost;
+            if new_dist < *dist.get(&v).unwrap_or(&f64::INFINITY) {
+                dist.insert(v, new_dist);
+                prev.insert(v, u);
+                if !*in_queue.get(&v).unwrap_or(&false) {
+                    queue.push_back(v);
+                    in_queue.insert(v, true);
+                }
+            }
+        }
+    }
+
+    if !dist.contains_key(&sink) {
+        return None;
+    }
+    let mut path = vec![sink];
+    let mut cur = sink;
+    while cur != source {
+        cur = prev[&cur];
+        path.push(cur);
+    }
+    path.reverse();
+    Some(path)
+}
+
+/// Computes the minimum-cost maximum flow between `source` and `sink`, where `capacity_prop`
+/// supplies per-edge capacity and `cost_prop` supplies the per-unit cost of sending flow along
+/// each edge. Reverse (residual) edges carry the negated cost of their forward counterpart, so
+/// the augmenting-path search uses SPFA rather than plain BFS to correctly handle negative
+/// edge weights.
+///
+/// # Returns
+/// `Ok(None)` if `source` or `sink` does not exist in this view, otherwise a [`FlowResult`] with
+/// the total flow, its total cost, and the realized per-edge flow.
+pub fn min_cost_flow<G: StaticGraphViewOps>(
+    graph: &G,
+    source: u64,
+    sink: u64,
+    capacity_prop: &str,
+    cost_prop: &str,
+) -> Result<Option<FlowResult>, GraphError> {
+    let Some(source) = graph.node(source).map(|v| v.node) else {
+        return Ok(None);
+    };
+    let Some(sink) = graph.node(sink).map(|v| v.node) else {
+        return Ok(None);
+    };
+    if source == sink {
+        return Ok(Some(FlowResult {
+            total_flow: 0.0,
+            total_cost: 0.0,
+            edge_flows: HashMap::new(),
+        }));
+    }
+
+    let (mut residual, _) = residual_graph(graph, capacity_prop);
+    let mut cost: HashMap<(VID, VID), f64> = HashMap::new();
+    for edge in graph.edges() {
+        let c = edge
+            .properties()
+            .temporal()
+            .get(cost_prop)
+            .and_then(|v| v.latest())
+            .and_then(|v| v.into_f64())
+            .unwrap_or(0.0);
+        let src = edge.src().node;
+        let dst = edge.dst().node;
+        cost.insert((src, dst), c);
+        cost.entry((dst, src)).or_insert(-c);
+    }
+
+    let mut total_flow = 0.0;
+    let mut total_cost = 0.0;
+
+    while let Some(path) = spfa_augmenting_path(&residual, &cost, source, sink) {
+        let bottleneck = path
+            .windows(2)
+            .map(|pair| residual[&pair[0]][&pair[1]])
+            .fold(f64::INFINITY, f64::min);
+
+        for pair in path.windows(2) {
+            let (u, v) = (pair[0], pair[1]);
+            *residual.get_mut(&u).unwrap().get_mut(&v).unwrap() -= bottleneck;
+            *residual.get_mut(&v).unwrap().get_mut(&u).unwrap() += bottleneck;
+            total_cost += bottleneck * cost.get(&(u, v)).copied().unwrap_or(0.0);
+        }
+        total_flow += bottleneck;
+    }
+
+    let edge_flows = flows_from_residual(graph, capacity_prop, &residual);
+
+    Ok(Some(FlowResult {
+        total_flow,
+        total_cost,
+        edge_flows,
+    }))
+}
+
+/// Fluent access to [`max_flow`] directly on a graph view, so it composes with `.window(..)` and
+/// `.layer(..)` like the rest of the query API, e.g. `g.window(a, b).max_flow(src, dst, "cap")`.
+pub trait MaxFlowOps<G: StaticGraphViewOps> {
+    /// See [`max_flow`].
+    fn max_flow(
+        &self,
+        source: u64,
+        sink: u64,
+        capacity_prop: &str,
+    ) -> Result<Option<FlowResult>, GraphError>;
+}
+
+impl<G: StaticGraphViewOps> MaxFlowOps<G> for G {
+    fn max_flow(
+        &self,
+        source: u64,
+        sink: u64,
+        capacity_prop: &str,
+    ) -> Result<Option<FlowResult>, GraphError> {
+        max_flow(self, source, sink, capacity_prop)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{max_flow, min_cost_flow, MaxFlowOps};
+    use crate::{
+        core::Prop,
+        db::{api::mutation::AdditionOps, graph::graph::Graph},
+        prelude::NodeViewOps,
+    };
+
+    // Two vertex-disjoint s-t paths with different bottlenecks: s-a-t (capacity 2) and
+    // s-b-t (capacity 4), so the expected max flow (6) and per-edge split are hand-checkable.
+    fn two_path_graph() -> Graph {
+        let g = Graph::new();
+        g.add_edge(0, "s", "a", [("capacity".to_string(), Prop::F64(3.0))], None)
+            .unwrap();
+        g.add_edge(0, "a", "t", [("capacity".to_string(), Prop::F64(2.0))], None)
+            .unwrap();
+        g.add_edge(0, "s", "b", [("capacity".to_string(), Prop::F64(5.0))], None)
+            .unwrap();
+        g.add_edge(0, "b", "t", [("capacity".to_string(), Prop::F64(4.0))], None)
+            .unwrap();
+        g
+    }
+
+    #[test]
+    fn max_flow_saturates_both_paths_at_their_bottleneck() {
+        let g = two_path_graph();
+        let result = max_flow(&g, g.node("s").unwrap().id(), g.node("t").unwrap().id(), "capacity")
+            .unwrap()
+            .unwrap();
+        assert_eq!(result.total_flow, 6.0);
+        let s = g.node("s").unwrap().id();
+        let a = g.node("a").unwrap().id();
+        let b = g.node("b").unwrap().id();
+        let t = g.node("t").unwrap().id();
+        assert_eq!(result.edge_flows.get(&(s, a)), Some(&2.0));
+        assert_eq!(result.edge_flows.get(&(a, t)), Some(&2.0));
+        assert_eq!(result.edge_flows.get(&(s, b)), Some(&4.0));
+        assert_eq!(result.edge_flows.get(&(b, t)), Some(&4.0));
+    }
+
+    #[test]
+    fn max_flow_returns_none_for_missing_source_or_sink() {
+        let g = two_path_graph();
+        let t = g.node("t").unwrap().id();
+        assert_eq!(max_flow(&g, 999, t, "capacity").unwrap(), None);
+    }
+
+    #[test]
+    fn max_flow_is_zero_when_source_and_sink_are_the_same_node() {
+        let g = two_path_graph();
+        let s = g.node("s").unwrap().id();
+        let result = max_flow(&g, s, s, "capacity").unwrap().unwrap();
+        assert_eq!(result.total_flow, 0.0);
+        assert!(result.edge_flows.is_empty());
+    }
+
+    #[test]
+    fn min_cost_flow_is_zero_when_source_and_sink_are_the_same_node() {
+        let g = two_path_graph();
+        let s = g.node("s").unwrap().id();
+        let result = min_cost_flow(&g, s, s, "capacity", "capacity")
+            .unwrap()
+            .unwrap();
+        assert_eq!(result.total_flow, 0.0);
+        assert_eq!(result.total_cost, 0.0);
+        assert!(result.edge_flows.is_empty());
+    }
+
+    #[test]
+    fn min_cost_flow_prefers_the_cheap_path_before_spilling_onto_the_expensive_one() {
+        let g = Graph::new();
+        g.add_edge(
+            0,
+            "s",
+            "a",
+            [
+                ("capacity".to_string(), Prop::F64(5.0)),
+                ("cost".to_string(), Prop::F64(1.0)),
+            ],
+            None,
+        )
+        .unwrap();
+        g.add_edge(
+            0,
+            "a",
+            "t",
+            [
+                ("capacity".to_string(), Prop::F64(5.0)),
+                ("cost".to_string(), Prop::F64(1.0)),
+            ],
+            None,
+        )
+        .unwrap();
+        g.add_edge(
+            0,
+            "s",
+            "b",
+            [
+                ("capacity".to_string(), Prop::F64(5.0)),
+                ("cost".to_string(), Prop::F64(10.0)),
+            ],
+            None,
+        )
+        .unwrap();
+        g.add_edge(
+            0,
+            "b",
+            "t",
+            [
+                ("capacity".to_string(), Prop::F64(5.0)),
+                ("cost".to_string(), Prop::F64(10.0)),
+            ],
+            None,
+        )
+        .unwrap();
+
+        let s = g.node("s").unwrap().id();
+        let t = g.node("t").unwrap().id();
+        let result = min_cost_flow(&g, s, t, "capacity", "cost").unwrap().unwrap();
+        assert_eq!(result.total_flow, 10.0);
+        assert_eq!(result.total_cost, 110.0);
+    }
+
+    #[test]
+    fn max_flow_ops_matches_the_free_function() {
+        let g = two_path_graph();
+        let s = g.node("s").unwrap().id();
+        let t = g.node("t").unwrap().id();
+        let via_trait = g.max_flow(s, t, "capacity").unwrap().unwrap();
+        let via_function = max_flow(&g, s, t, "capacity").unwrap().unwrap();
+        assert_eq!(via_trait, via_function);
+    }
+}
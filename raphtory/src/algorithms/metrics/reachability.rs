@@ -0,0 +1,268 @@
+//! # Bit-Matrix Reachability
+//!
+//! Answers "can node `u` reach node `v`?" over a (possibly windowed) view in O(1) per query
+//! after a one-off fixpoint transitive-closure pass (each sweep costs up to O(N^3/64) word
+//! operations, repeated until nothing changes), instead of approximating it one hop at a
+//! time with neighbour iteration. Reachability is stored as a dense bit matrix: `ceil(N/64)`
+//! `u64` words per source row, with `set`/`contains` addressing a target's bit within its row.
+use crate::{
+    db::api::view::StaticGraphViewOps,
+    prelude::{EdgeViewOps, GraphViewOps, NodeViewOps},
+};
+use std::collections::{HashMap, HashSet};
+
+/// A dense `N x N` bit matrix, `ceil(N/64)` words per row.
+struct BitMatrix {
+    words_per_row: usize,
+    rows: Vec<u64>,
+}
+
+impl BitMatrix {
+    fn new(n: usize) -> Self {
+        let words_per_row = n.div_ceil(64).max(1);
+        Self {
+            words_per_row,
+            rows: vec![0u64; words_per_row * n.max(1)],
+        }
+    }
+
+    fn set(&mut self, u: usize, v: usize) {
+        self.rows[u * self.words_per_row + v / 64] |= 1 << (v % 64);
+    }
+
+    fn contains(&self, u: usize, v: usize) -> bool {
+        self.rows[u * self.words_per_row + v / 64] & (1 << (v % 64)) != 0
+    }
+
+    /// ORs row `src` into row `dst`, returning whether `dst`'s row changed.
+    fn or_row_into(&mut self, dst: usize, src: usize) -> bool {
+        let mut changed = false;
+        for w in 0..self.words_per_row {
+            let merged = self.rows[dst * self.words_per_row + w] | self.rows[src * self.words_per_row + w];
+            if merged != self.rows[dst * self.words_per_row + w] {
+                self.rows[dst * self.words_per_row + w] = merged;
+                changed = true;
+            }
+        }
+        changed
+    }
+
+    fn row_bits(&self, u: usize, n: usize) -> Vec<usize> {
+        (0..n).filter(|&v| self.contains(u, v)).collect()
+    }
+}
+
+/// Fixpoint transitive closure: repeatedly OR each reachable target's row into its source's row
+/// until a full pass makes no change.
+fn transitive_closure(matrix: &mut BitMatrix, n: usize) {
+    loop {
+        let mut changed = false;
+        for u in 0..n {
+            for v in matrix.row_bits(u, n) {
+                if v != u && matrix.or_row_into(u, v) {
+                    changed = true;
+                }
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+}
+
+/// A precomputed all-pairs reachability index over a graph view.
+pub struct ReachabilityIndex {
+    id_to_idx: HashMap<u64, usize>,
+    idx_to_id: Vec<u64>,
+    matrix: BitMatrix,
+}
+
+impl ReachabilityIndex {
+    /// Builds the index, seeding the matrix with edges active in `graph`'s current window/layer
+    /// selection and then closing it to a fixpoint.
+    pub fn build<G: StaticGraphViewOps>(graph: &G) -> Self {
+        let idx_to_id: Vec<u64> = graph.nodes().into_iter().map(|n| n.id()).collect();
+        let id_to_idx: HashMap<u64, usize> = idx_to_id
+            .iter()
+            .enumerate()
+            .map(|(i, &id)| (id, i))
+            .collect();
+        let n = idx_to_id.len();
+        let mut matrix = BitMatrix::new(n);
+
+        for edge in graph.edges() {
+            let src = id_to_idx[&edge.src().id()];
+            let dst = id_to_idx[&edge.dst().id()];
+            matrix.set(src, dst);
+        }
+
+        transitive_closure(&mut matrix, n);
+
+        Self {
+            id_to_idx,
+            idx_to_id,
+            matrix,
+        }
+    }
+
+    /// The set of node ids reachable from `source` (excluding `source` itself).
+    pub fn reachable_from(&self, source: u64) -> HashSet<u64> {
+        let Some(&ui) = self.id_to_idx.get(&source) else {
+            return HashSet::new();
+        };
+        self.matrix
+            .row_bits(ui, self.idx_to_id.len())
+            .into_iter()
+            .map(|i| self.idx_to_id[i])
+            .filter(|&id| id != source)
+            .collect()
+    }
+
+    /// Whether `target` is reachable from `source`.
+    pub fn reachable(&self, source: u64, target: u64) -> bool {
+        match (self.id_to_idx.get(&source), self.id_to_idx.get(&target)) {
+            (Some(&ui), Some(&vi)) => self.matrix.contains(ui, vi),
+            _ => false,
+        }
+    }
+}
+
+/// The time-respecting variant: a node is only reachable if there is a path whose edge
+/// timestamps are non-decreasing along the way. Activations are grouped by timestamp and each
+/// group is relaxed to a fixpoint before moving on to the next timestamp - "non-decreasing"
+/// allows same-timestamp hops to chain (`a -> b -> c` all at `t = 5` is a valid path), and a
+/// single pass in whatever order the activations happen to be in can visit `b -> c` before
+/// `a -> b` and silently miss that chain.
+pub fn temporal_reachable_from<G: StaticGraphViewOps>(graph: &G, source: u64) -> HashSet<u64> {
+    let mut activations: Vec<(i64, u64, u64)> = Vec::new();
+    for edge in graph.edges() {
+        let src = edge.src().id();
+        let dst = edge.dst().id();
+        for t in edge.history() {
+            activations.push((t, src, dst));
+        }
+    }
+    activations.sort_by_key(|&(t, _, _)| t);
+
+    let mut frontier = HashSet::new();
+    frontier.insert(source);
+
+    let mut i = 0;
+    while i < activations.len() {
+        let t = activations[i].0;
+        let mut j = i;
+        while j < activations.len() && activations[j].0 == t {
+            j += 1;
+        }
+        let group = &activations[i..j];
+
+        loop {
+            let mut changed = false;
+            for &(_, u, v) in group {
+                if frontier.contains(&u) && frontier.insert(v) {
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+
+        i = j;
+    }
+
+    frontier.remove(&source);
+    frontier
+}
+
+/// Fluent access to reachability queries directly on a graph view, e.g.
+/// `g.window(a, b).reachable_from(u)`.
+pub trait ReachabilityOps<G: StaticGraphViewOps> {
+    fn reachable_from(&self, source: u64) -> HashSet<u64>;
+    fn reachable(&self, source: u64, target: u64) -> bool;
+    fn temporal_reachable_from(&self, source: u64) -> HashSet<u64>;
+}
+
+impl<G: StaticGraphViewOps> ReachabilityOps<G> for G {
+    fn reachable_from(&self, source: u64) -> HashSet<u64> {
+        ReachabilityIndex::build(self).reachable_from(source)
+    }
+
+    fn reachable(&self, source: u64, target: u64) -> bool {
+        ReachabilityIndex::build(self).reachable(source, target)
+    }
+
+    fn temporal_reachable_from(&self, source: u64) -> HashSet<u64> {
+        temporal_reachable_from(self, source)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ReachabilityIndex, ReachabilityOps};
+    use crate::{
+        db::{api::mutation::AdditionOps, graph::graph::Graph},
+        prelude::*,
+    };
+    use std::collections::HashSet;
+
+    #[test]
+    fn structural_reachability_ignores_edge_timestamps() {
+        let g = Graph::new();
+        g.add_edge(0, "a", "b", NO_PROPS, None).unwrap();
+        g.add_edge(0, "b", "c", NO_PROPS, None).unwrap();
+        g.add_vertex(0, "d", NO_PROPS).unwrap();
+
+        let index = ReachabilityIndex::build(&g);
+        let a = g.node("a").unwrap().id();
+        let b = g.node("b").unwrap().id();
+        let c = g.node("c").unwrap().id();
+        let d = g.node("d").unwrap().id();
+
+        assert_eq!(index.reachable_from(a), HashSet::from([b, c]));
+        assert!(index.reachable(a, c));
+        assert!(!index.reachable(c, a));
+        assert!(!index.reachable(a, d));
+    }
+
+    #[test]
+    fn temporal_reachability_requires_non_decreasing_timestamps() {
+        let g = Graph::new();
+        g.add_edge(1, "a", "b", NO_PROPS, None).unwrap();
+        g.add_edge(2, "b", "c", NO_PROPS, None).unwrap();
+
+        let a = g.node("a").unwrap().id();
+        let c = g.node("c").unwrap().id();
+        assert!(g.temporal_reachable_from(a).contains(&c));
+    }
+
+    #[test]
+    fn temporal_reachability_rejects_a_path_that_goes_backwards_in_time() {
+        let g = Graph::new();
+        // b -> c happens before a -> b, so a cannot actually reach c in time order even though
+        // it is structurally reachable.
+        g.add_edge(3, "b", "c", NO_PROPS, None).unwrap();
+        g.add_edge(5, "a", "b", NO_PROPS, None).unwrap();
+
+        let a = g.node("a").unwrap().id();
+        let c = g.node("c").unwrap().id();
+        assert!(!g.temporal_reachable_from(a).contains(&c));
+    }
+
+    #[test]
+    fn temporal_reachability_chains_same_timestamp_hops_regardless_of_insertion_order() {
+        let g = Graph::new();
+        // All three edges fire at the same instant t = 5. Inserted in reverse hop order (c -> d,
+        // then b -> c, then a -> b) so a single forward pass over activations in insertion order
+        // would see "b -> c" and "c -> d" before the "a -> b" that makes them reachable at all.
+        g.add_edge(5, "c", "d", NO_PROPS, None).unwrap();
+        g.add_edge(5, "b", "c", NO_PROPS, None).unwrap();
+        g.add_edge(5, "a", "b", NO_PROPS, None).unwrap();
+
+        let a = g.node("a").unwrap().id();
+        let b = g.node("b").unwrap().id();
+        let c = g.node("c").unwrap().id();
+        let d = g.node("d").unwrap().id();
+        assert_eq!(g.temporal_reachable_from(a), HashSet::from([b, c, d]));
+    }
+}
@@ -0,0 +1,180 @@
+//! # Minimum Spanning Tree
+//!
+//! Computes the minimum spanning tree of the current view (respecting whatever window/layer it
+//! was built with) using Kruskal's algorithm, and materializes the result as a new [`Graph`] so
+//! it can be re-queried, windowed, and saved like any other graph.
+use crate::{
+    core::entities::VID,
+    db::graph::{edge::EdgeView, graph::Graph},
+    prelude::{AdditionOps, EdgeViewOps, GraphViewOps, VertexViewOps, NO_PROPS},
+};
+use std::collections::HashMap;
+
+/// A union-find (disjoint-set) structure with path compression and union-by-rank, keyed by
+/// internal vertex ids.
+struct UnionFind {
+    parent: HashMap<VID, VID>,
+    rank: HashMap<VID, usize>,
+}
+
+impl UnionFind {
+    fn new() -> Self {
+        Self {
+            parent: HashMap::new(),
+            rank: HashMap::new(),
+        }
+    }
+
+    fn make_set(&mut self, v: VID) {
+        self.parent.entry(v).or_insert(v);
+        self.rank.entry(v).or_insert(0);
+    }
+
+    fn find(&mut self, v: VID) -> VID {
+        let parent = self.parent[&v];
+        if parent != v {
+            let root = self.find(parent);
+            self.parent.insert(v, root);
+            root
+        } else {
+            v
+        }
+    }
+
+    /// Unions the sets containing `a` and `b`, returning `true` if they were in different sets.
+    fn union(&mut self, a: VID, b: VID) -> bool {
+        let root_a = self.find(a);
+        let root_b = self.find(b);
+        if root_a == root_b {
+            return false;
+        }
+        let rank_a = self.rank[&root_a];
+        let rank_b = self.rank[&root_b];
+        match rank_a.cmp(&rank_b) {
+            std::cmp::Ordering::Less => {
+                self.parent.insert(root_a, root_b);
+            }
+            std::cmp::Ordering::Greater => {
+                self.parent.insert(root_b, root_a);
+            }
+            std::cmp::Ordering::Equal => {
+                self.parent.insert(root_b, root_a);
+                self.rank.insert(root_a, rank_a + 1);
+            }
+        }
+        true
+    }
+}
+
+/// Computes the minimum spanning tree of `graph` using `weight_fn` to read a weight off each
+/// edge, and returns it as a new [`Graph`] containing only the accepted MST edges with their
+/// original timestamps and properties preserved.
+pub fn min_spanning_tree<G, F>(graph: &G, weight_fn: F) -> Graph
+where
+    G: GraphViewOps,
+    F: Fn(&EdgeView<G, G>) -> f64,
+{
+    let mut edges: Vec<(f64, EdgeView<G, G>)> = graph
+        .edges()
+        .into_iter()
+        .map(|e| (weight_fn(&e), e))
+        .collect();
+    edges.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let mut uf = UnionFind::new();
+    for vertex in graph.vertices() {
+        uf.make_set(vertex.vertex);
+    }
+
+    let mst = Graph::new();
+    for vertex in graph.vertices() {
+        mst.add_vertex(vertex.earliest_time().unwrap_or(0), vertex.id(), NO_PROPS)
+            .expect("adding a vertex to the MST graph should not fail");
+    }
+
+    let num_vertices = graph.num_vertices();
+    let mut accepted = 0usize;
+    if num_vertices == 0 {
+        return mst;
+    }
+
+    for (_, edge) in edges {
+        if accepted >= num_vertices.saturating_sub(1) {
+            break;
+        }
+        if uf.union(edge.src().vertex, edge.dst().vertex) {
+            for t in edge.history() {
+                mst.add_edge(
+                    t,
+                    edge.src().id(),
+                    edge.dst().id(),
+                    edge.properties()
+                        .temporal()
+                        .iter()
+                        .filter_map(|(k, v)| v.latest().map(|v| (k.to_string(), v)))
+                        .collect::<Vec<_>>(),
+                    None,
+                )
+                .expect("adding an MST edge should not fail");
+            }
+            accepted += 1;
+        }
+    }
+
+    mst
+}
+
+#[cfg(test)]
+mod test {
+    use super::min_spanning_tree;
+    use crate::{
+        core::Prop,
+        db::{api::mutation::AdditionOps, graph::graph::Graph},
+        prelude::*,
+    };
+
+    fn weight(edge: &crate::db::graph::edge::EdgeView<Graph, Graph>) -> f64 {
+        edge.properties()
+            .get("weight")
+            .and_then(|v| v.into_f64())
+            .unwrap_or(0.0)
+    }
+
+    // A 4-cycle a-b-c-d-a (weights 1,2,3,4) plus a diagonal a-c (weight 5). Kruskal must reject
+    // the diagonal (it would close a cycle) and the final d-a edge (the tree is already
+    // spanning after the first three cheapest edges), leaving a path of total weight 6.
+    fn cycle_with_diagonal() -> Graph {
+        let g = Graph::new();
+        for (src, dst, w) in [
+            ("a", "b", 1.0),
+            ("b", "c", 2.0),
+            ("c", "d", 3.0),
+            ("d", "a", 4.0),
+            ("a", "c", 5.0),
+        ] {
+            g.add_edge(0, src, dst, [("weight".to_string(), Prop::F64(w))], None)
+                .unwrap();
+        }
+        g
+    }
+
+    #[test]
+    fn picks_the_three_cheapest_non_cycle_forming_edges() {
+        let g = cycle_with_diagonal();
+        let mst = min_spanning_tree(&g, weight);
+
+        assert_eq!(mst.num_vertices(), 4);
+        assert_eq!(mst.num_edges(), 3);
+
+        let total_weight: f64 = mst.edges().into_iter().map(|e| weight(&e)).sum();
+        assert_eq!(total_weight, 6.0);
+    }
+
+    #[test]
+    fn empty_graph_yields_an_empty_tree() {
+        let g = Graph::new();
+        let mst = min_spanning_tree(&g, weight);
+        assert_eq!(mst.num_vertices(), 0);
+        assert_eq!(mst.num_edges(), 0);
+    }
+}
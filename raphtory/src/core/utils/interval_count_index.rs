@@ -0,0 +1,88 @@
+//! # BITS-Style Interval Counting Index
+//!
+//! Answers "how many intervals overlap `[a, b)`?" without materializing them, for workloads
+//! (degree-over-time, activity histograms) that only need the count. The approach is the one
+//! used by interval-counting BITS indexes: precompute the sorted array of every interval's
+//! `start` and the sorted array of every interval's `end`, then
+//! `overlapping = total - (#ending at or before a) - (#starting at or after b)`, each term a
+//! single binary search (`partition_point`) rather than a scan.
+pub struct IntervalCountIndex {
+    starts: Vec<i64>,
+    ends: Vec<i64>,
+}
+
+impl IntervalCountIndex {
+    /// Builds the index from an unsorted list of `[start, end)` intervals.
+    pub fn build(intervals: impl IntoIterator<Item = (i64, i64)>) -> Self {
+        let (mut starts, mut ends): (Vec<i64>, Vec<i64>) = intervals.into_iter().unzip();
+        starts.sort_unstable();
+        ends.sort_unstable();
+        Self { starts, ends }
+    }
+
+    /// Total number of intervals held in the index.
+    pub fn len(&self) -> usize {
+        self.starts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.starts.is_empty()
+    }
+
+    /// Number of intervals overlapping the half-open window `[a, b)`, in `O(log n)`.
+    pub fn count_overlapping(&self, a: i64, b: i64) -> usize {
+        if b <= a || self.starts.is_empty() {
+            return 0;
+        }
+        let total = self.starts.len();
+        // Intervals that ended at or before `a` can't overlap `[a, b)`.
+        let ended_before = self.ends.partition_point(|&end| end <= a);
+        // Intervals that start at or after `b` can't overlap `[a, b)` either.
+        let starts_after = total - self.starts.partition_point(|&start| start < b);
+        total - ended_before - starts_after
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::IntervalCountIndex;
+
+    fn sample_index() -> IntervalCountIndex {
+        IntervalCountIndex::build(vec![(0, 5), (5, 10), (2, 8), (20, 25)])
+    }
+
+    #[test]
+    fn touching_intervals_are_not_counted_as_overlapping() {
+        let index = sample_index();
+        // (0, 5) ends exactly where the window starts, so it must not be counted; (5, 10) and
+        // (2, 8) both genuinely overlap [5, 6).
+        assert_eq!(index.count_overlapping(5, 6), 2);
+    }
+
+    #[test]
+    fn counts_every_interval_genuinely_overlapping_the_window() {
+        let index = sample_index();
+        assert_eq!(index.count_overlapping(1, 3), 2); // (0,5) and (2,8)
+        assert_eq!(index.count_overlapping(0, 100), 4); // everything
+        assert_eq!(index.count_overlapping(12, 18), 0); // the gap between clusters
+    }
+
+    #[test]
+    fn an_empty_or_backwards_window_counts_as_zero() {
+        let index = sample_index();
+        assert_eq!(index.count_overlapping(5, 5), 0);
+        assert_eq!(index.count_overlapping(10, 3), 0);
+    }
+
+    #[test]
+    fn an_empty_index_always_counts_zero_and_reports_its_length() {
+        let index = IntervalCountIndex::build(Vec::new());
+        assert!(index.is_empty());
+        assert_eq!(index.len(), 0);
+        assert_eq!(index.count_overlapping(0, 100), 0);
+
+        let index = sample_index();
+        assert!(!index.is_empty());
+        assert_eq!(index.len(), 4);
+    }
+}
@@ -0,0 +1,182 @@
+//! # Augmented Interval Index
+//!
+//! Answers "which intervals overlap `[a, b)`?" in roughly `O(log n + k)` instead of scanning
+//! every edge and testing `active(t)` point by point. This is an array-backed augmented tree in
+//! the style of cgranges/COITrees: intervals are sorted by start into a flat array, which doubles
+//! as the implicit shape of a balanced binary search tree — for a sub-range `[lo, hi)` the node at
+//! `mid = lo + (hi - lo) / 2` is the subtree root, with `[lo, mid)`/`[mid + 1, hi)` its left/right
+//! subtrees. Unlike a fixed stride/level scheme, this mid-split assignment is well-defined for
+//! every `n`, not just `2^L - 1`, so there is no separate "what if the tree shape is incomplete"
+//! case to get wrong. Each node is augmented with the maximum `end` anywhere in its subtree so a
+//! query can prune subtrees that end before the query starts.
+/// A persistent index over `(start, end, value)` intervals, queryable for overlap with a
+/// half-open window `[a, b)`.
+pub struct IntervalIndex<T> {
+    // Sorted by `start`; `max_end[i]` is the maximum `end` in the subtree rooted at `i`.
+    intervals: Vec<(i64, i64, T)>,
+    max_end: Vec<i64>,
+}
+
+impl<T> IntervalIndex<T> {
+    /// Builds the index from an unsorted list of `(start, end, value)` intervals.
+    pub fn build(mut intervals: Vec<(i64, i64, T)>) -> Self {
+        intervals.sort_by(|a, b| a.0.cmp(&b.0));
+        let n = intervals.len();
+        let mut max_end: Vec<i64> = intervals.iter().map(|&(_, end, _)| end).collect();
+        if n > 0 {
+            Self::augment(0, n, &mut max_end);
+        }
+        Self { intervals, max_end }
+    }
+
+    /// Post-order augmentation over the balanced BST implicitly shaped by `[lo, hi)`: the node at
+    /// `mid` absorbs the max over its (already-augmented) left and right subtrees. Returns the
+    /// max `end` anywhere in `[lo, hi)`, so the caller one level up can fold it straight in.
+    fn augment(lo: usize, hi: usize, max_end: &mut [i64]) -> i64 {
+        let mid = lo + (hi - lo) / 2;
+        let mut subtree_max = max_end[mid];
+        if mid > lo {
+            subtree_max = subtree_max.max(Self::augment(lo, mid, max_end));
+        }
+        if mid + 1 < hi {
+            subtree_max = subtree_max.max(Self::augment(mid + 1, hi, max_end));
+        }
+        max_end[mid] = subtree_max;
+        subtree_max
+    }
+
+    /// Returns every value whose interval overlaps the half-open window `[start, end)`, via a
+    /// non-recursive descent that pushes and pops `[lo, hi)` ranges on an explicit worklist stack
+    /// (it is not a stackless walk) and prunes subtrees whose `max_end` is at or before `start`.
+    pub fn query(&self, start: i64, end: i64) -> Vec<&T> {
+        let n = self.intervals.len();
+        if n == 0 || end <= start {
+            return Vec::new();
+        }
+
+        let mut results = Vec::new();
+        let mut worklist = vec![(0usize, n)];
+
+        while let Some((lo, hi)) = worklist.pop() {
+            if lo >= hi {
+                continue;
+            }
+            let mid = lo + (hi - lo) / 2;
+            if self.max_end[mid] <= start {
+                continue;
+            }
+
+            if mid + 1 < hi {
+                worklist.push((mid + 1, hi));
+            }
+            if mid > lo {
+                worklist.push((lo, mid));
+            }
+
+            let (s, e, value) = &self.intervals[mid];
+            if *s < end && *e > start {
+                results.push(value);
+            }
+        }
+
+        results
+    }
+
+    /// Number of intervals held in the index.
+    pub fn len(&self) -> usize {
+        self.intervals.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::IntervalIndex;
+    use std::collections::HashSet;
+
+    fn sample_index() -> IntervalIndex<&'static str> {
+        IntervalIndex::build(vec![(0, 5, "a"), (5, 10, "b"), (2, 8, "c")])
+    }
+
+    #[test]
+    fn touching_endpoints_do_not_overlap() {
+        let index = sample_index();
+        // A zero-width window [5, 5) covers no time at all, so nothing overlaps it. In [8, 10),
+        // "c" ends exactly where the query starts, so a half-open window must leave it out.
+        let hits: HashSet<&str> = index.query(5, 5).into_iter().copied().collect();
+        assert!(hits.is_empty());
+
+        let hits: HashSet<&str> = index.query(8, 10).into_iter().copied().collect();
+        assert_eq!(hits, HashSet::from(["b"]));
+    }
+
+    #[test]
+    fn overlapping_intervals_are_all_returned() {
+        let index = sample_index();
+        let hits: HashSet<&str> = index.query(4, 6).into_iter().copied().collect();
+        assert_eq!(hits, HashSet::from(["a", "b", "c"]));
+    }
+
+    #[test]
+    fn an_empty_or_backwards_query_window_returns_nothing() {
+        let index = sample_index();
+        assert!(index.query(3, 3).is_empty());
+        assert!(index.query(10, 3).is_empty());
+    }
+
+    #[test]
+    fn an_empty_index_has_no_results_and_reports_its_length() {
+        let index: IntervalIndex<&str> = IntervalIndex::build(Vec::new());
+        assert!(index.is_empty());
+        assert_eq!(index.len(), 0);
+        assert!(index.query(0, 100).is_empty());
+
+        let index = sample_index();
+        assert!(!index.is_empty());
+        assert_eq!(index.len(), 3);
+    }
+
+    #[test]
+    fn a_lone_outlier_at_a_non_perfect_size_is_still_found() {
+        // n = 5 is not of the form 2^L - 1. A fixed stride/level scheme has to special-case
+        // sizes like this; the mid-split tree shape doesn't, so this is really a regression test
+        // for "don't go back to a stride-based build".
+        let index = IntervalIndex::build(vec![
+            (0, 1, "a"),
+            (1, 2, "b"),
+            (2, 3, "c"),
+            (3, 4, "d"),
+            (100, 200, "e"),
+        ]);
+        assert_eq!(index.query(150, 160).into_iter().collect::<Vec<_>>(), vec![&"e"]);
+    }
+
+    #[test]
+    fn non_perfect_sizes_still_find_every_overlap() {
+        // Exercise a handful of non-"2^L - 1" sizes: each index is a chain of unit intervals
+        // with one outlier at the far right, and every interval must be individually findable.
+        for n in [5usize, 9, 10, 11, 13] {
+            let mut intervals: Vec<(i64, i64, &'static str)> = Vec::new();
+            let labels: &[&str] = &[
+                "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12",
+            ];
+            for i in 0..n {
+                let start = (i as i64) * 10;
+                intervals.push((start, start + 1, labels[i]));
+            }
+            let index = IntervalIndex::build(intervals);
+            for i in 0..n {
+                let start = (i as i64) * 10;
+                let hits: HashSet<&str> = index.query(start, start + 1).into_iter().copied().collect();
+                assert!(
+                    hits.contains(labels[i]),
+                    "n={n}: expected to find interval {i} via query({start}, {})",
+                    start + 1
+                );
+            }
+        }
+    }
+}
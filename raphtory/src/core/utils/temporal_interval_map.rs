@@ -0,0 +1,139 @@
+//! # Piecewise-Constant Temporal Property Store
+//!
+//! A property that changes over time is usually modeled as a log of timestamped updates; a
+//! [`TemporalIntervalMap`] instead stores it as a step function over non-overlapping, sorted
+//! half-open intervals `[start, end)`, each mapping to the value that held throughout. This
+//! turns "what was this property's value at time `t`?" and "did it have a value during window
+//! `W`?" into `O(log n)` lookups instead of replaying the update log.
+use std::collections::BTreeMap;
+
+/// A half-open time interval `[start, end)`.
+pub type Interval = (i64, i64);
+
+/// An ordered interval-to-value map with the invariant that no two stored intervals overlap,
+/// keyed internally by each interval's start.
+pub struct TemporalIntervalMap<V> {
+    // Keyed by interval start; value is `(end, V)`.
+    intervals: BTreeMap<i64, (i64, V)>,
+}
+
+impl<V> Default for TemporalIntervalMap<V> {
+    fn default() -> Self {
+        Self {
+            intervals: BTreeMap::new(),
+        }
+    }
+}
+
+impl<V: Clone> TemporalIntervalMap<V> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The value active at time `t`, if any.
+    pub fn get_at_point(&self, t: i64) -> Option<&V> {
+        self.intervals
+            .range(..=t)
+            .next_back()
+            .filter(|(_, (end, _))| t < *end)
+            .map(|(_, (_, v))| v)
+    }
+
+    /// Whether any stored interval overlaps the half-open window `[start, end)`.
+    pub fn overlaps(&self, window: Interval) -> bool {
+        let (start, end) = window;
+        if end <= start {
+            return false;
+        }
+        self.intervals
+            .range(..end)
+            .next_back()
+            .is_some_and(|(&s, &(e, _))| s < end && e > start)
+    }
+
+    /// Yields `(interval, &value)` pairs in time order.
+    pub fn iter(&self) -> impl Iterator<Item = (Interval, &V)> {
+        self.intervals.iter().map(|(&s, (e, v))| ((s, *e), v))
+    }
+
+    /// Inserts `value` over `[start, end)`, splitting or truncating any existing interval it
+    /// overlaps so the non-overlapping invariant always holds. The new interval wins wherever it
+    /// overlaps an old one.
+    pub fn insert(&mut self, start: i64, end: i64, value: V) {
+        if end <= start {
+            return;
+        }
+
+        // Collect the existing intervals that the new one touches, so they can be trimmed or
+        // dropped without mutating the map while iterating it.
+        let overlapping: Vec<(i64, i64, V)> = self
+            .intervals
+            .range(..end)
+            .filter(|&(&s, &(e, _))| s < end && e > start)
+            .map(|(&s, (e, v))| (s, *e, v.clone()))
+            .collect();
+
+        for (s, e, v) in overlapping {
+            self.intervals.remove(&s);
+            // The part of the old interval before the new one starts survives untouched.
+            if s < start {
+                self.intervals.insert(s, (start, v.clone()));
+            }
+            // The part of the old interval after the new one ends survives untouched.
+            if e > end {
+                self.intervals.insert(end, (e, v));
+            }
+        }
+
+        self.intervals.insert(start, (end, value));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::TemporalIntervalMap;
+
+    fn two_intervals() -> TemporalIntervalMap<&'static str> {
+        let mut map = TemporalIntervalMap::new();
+        map.insert(0, 5, "a");
+        map.insert(5, 10, "b");
+        map
+    }
+
+    #[test]
+    fn get_at_point_resolves_to_the_interval_containing_it() {
+        let map = two_intervals();
+        assert_eq!(map.get_at_point(4), Some(&"a"));
+        assert_eq!(map.get_at_point(5), Some(&"b"));
+        assert_eq!(map.get_at_point(9), Some(&"b"));
+        assert_eq!(map.get_at_point(10), None);
+        assert_eq!(map.get_at_point(-1), None);
+    }
+
+    #[test]
+    fn overlaps_treats_touching_endpoints_as_not_overlapping() {
+        let mut map = TemporalIntervalMap::new();
+        map.insert(0, 5, "a");
+
+        // [5, 8) starts exactly where "a" ends, so a half-open interval must not count it.
+        assert!(!map.overlaps((5, 8)));
+        // [4, 8) still includes t=4, which "a" covers.
+        assert!(map.overlaps((4, 8)));
+        // A zero-width or backwards window never overlaps anything.
+        assert!(!map.overlaps((5, 5)));
+        assert!(!map.overlaps((8, 5)));
+    }
+
+    #[test]
+    fn insert_splits_an_existing_interval_around_the_new_one() {
+        let mut map = TemporalIntervalMap::new();
+        map.insert(0, 10, "a");
+        map.insert(3, 6, "b");
+
+        let entries: Vec<((i64, i64), &&str)> = map.iter().collect();
+        assert_eq!(
+            entries,
+            vec![((0, 3), &"a"), ((3, 6), &"b"), ((6, 10), &"a")]
+        );
+    }
+}
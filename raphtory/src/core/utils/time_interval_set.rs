@@ -0,0 +1,191 @@
+//! # Set-Combine Operations Over Time Intervals
+//!
+//! A [`TimeIntervalSet`] is a coalesced, sorted collection of non-overlapping half-open
+//! intervals `[start, end)`, typically built from an edge's (or event stream's) raw activation
+//! timestamps. It answers questions like "when was this edge active at all, compactly?" and,
+//! via [`TimeIntervalSet::intersection`]/[`union`](TimeIntervalSet::union)/
+//! [`difference`](TimeIntervalSet::difference), "when were two edges simultaneously active?" —
+//! without the caller replaying per-timestamp `active(t)` checks.
+/// A half-open time interval `[start, end)`.
+pub type Interval = (i64, i64);
+
+/// A sorted, non-overlapping (and non-adjacent, once [`merged`](Self::merged) has run) set of
+/// time intervals.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TimeIntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl TimeIntervalSet {
+    /// Builds the coalesced set from raw (possibly overlapping, possibly unsorted) `[start,
+    /// end)` ranges: sorts by start, then sweeps, extending the current run whenever the next
+    /// interval's start is at or before the current run's end. Empty ranges (`start >= end`) are
+    /// dropped.
+    pub fn merged(raw: impl IntoIterator<Item = Interval>) -> Self {
+        let mut ranges: Vec<Interval> = raw.into_iter().filter(|(s, e)| s < e).collect();
+        ranges.sort_by_key(|&(s, _)| s);
+
+        let mut intervals: Vec<Interval> = Vec::with_capacity(ranges.len());
+        for (start, end) in ranges {
+            match intervals.last_mut() {
+                Some((_, last_end)) if start <= *last_end => {
+                    *last_end = (*last_end).max(end);
+                }
+                _ => intervals.push((start, end)),
+            }
+        }
+        Self { intervals }
+    }
+
+    /// Builds the set directly from raw timestamps, treating each `t` as the instantaneous
+    /// interval `[t, t + 1)` before coalescing.
+    pub fn from_timestamps(timestamps: impl IntoIterator<Item = i64>) -> Self {
+        Self::merged(timestamps.into_iter().map(|t| (t, t + 1)))
+    }
+
+    /// The coalesced intervals, in time order.
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// The union of `self` and `other`: every interval covered by either, coalesced.
+    pub fn union(&self, other: &Self) -> Self {
+        Self::merged(
+            self.intervals
+                .iter()
+                .copied()
+                .chain(other.intervals.iter().copied()),
+        )
+    }
+
+    /// The intervals during which both sets are simultaneously active: a linear two-pointer
+    /// sweep emitting `[max(a.start, b.start), min(a.end, b.end))` whenever that range is
+    /// non-empty, then advancing whichever interval ends first.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut result = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while i < self.intervals.len() && j < other.intervals.len() {
+            let (a_start, a_end) = self.intervals[i];
+            let (b_start, b_end) = other.intervals[j];
+
+            let start = a_start.max(b_start);
+            let end = a_end.min(b_end);
+            if start < end {
+                result.push((start, end));
+            }
+
+            if a_end <= b_end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { intervals: result }
+    }
+
+    /// The intervals covered by `self` but not by `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut result = Vec::new();
+        for &(start, end) in &self.intervals {
+            // Walk the pieces of `other` that could carve into this interval, tracking how much
+            // of `[start, end)` remains uncovered so far.
+            let mut cursor = start;
+            for &(o_start, o_end) in &other.intervals {
+                if o_end <= cursor || o_start >= end {
+                    continue;
+                }
+                if o_start > cursor {
+                    result.push((cursor, o_start));
+                }
+                cursor = cursor.max(o_end);
+                if cursor >= end {
+                    break;
+                }
+            }
+            if cursor < end {
+                result.push((cursor, end));
+            }
+        }
+        Self { intervals: result }.merged_self()
+    }
+
+    /// Re-coalesces `self`'s own intervals; used internally after [`difference`](Self::difference)
+    /// may have produced adjacent pieces.
+    fn merged_self(self) -> Self {
+        Self::merged(self.intervals)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::TimeIntervalSet;
+
+    #[test]
+    fn merged_coalesces_touching_intervals_but_not_ones_with_a_gap() {
+        // [0, 5) and [5, 10) touch (share the boundary point) and must coalesce into one run;
+        // [12, 15) has a real gap before it and must stay separate.
+        let set = TimeIntervalSet::merged(vec![(0, 5), (5, 10), (12, 15)]);
+        assert_eq!(set.intervals(), &[(0, 10), (12, 15)]);
+    }
+
+    #[test]
+    fn merged_drops_empty_ranges_and_sorts_unsorted_input() {
+        let set = TimeIntervalSet::merged(vec![(10, 15), (3, 3), (0, 5)]);
+        assert_eq!(set.intervals(), &[(0, 5), (10, 15)]);
+    }
+
+    #[test]
+    fn from_timestamps_coalesces_consecutive_instants_into_one_run() {
+        let set = TimeIntervalSet::from_timestamps(vec![1, 2, 3, 10]);
+        assert_eq!(set.intervals(), &[(1, 4), (10, 11)]);
+    }
+
+    #[test]
+    fn union_merges_overlapping_runs_from_both_sets() {
+        let a = TimeIntervalSet::merged(vec![(0, 5)]);
+        let b = TimeIntervalSet::merged(vec![(3, 8), (20, 25)]);
+        assert_eq!(a.union(&b).intervals(), &[(0, 8), (20, 25)]);
+    }
+
+    #[test]
+    fn intersection_keeps_only_the_overlapping_portion_and_touching_runs_are_excluded() {
+        let a = TimeIntervalSet::merged(vec![(0, 5), (10, 15)]);
+        let b = TimeIntervalSet::merged(vec![(3, 10), (15, 20)]);
+        // [0,5) x [3,10) -> [3,5); [10,15) x [3,10) -> nothing (touching, not overlapping);
+        // [10,15) x [15,20) -> nothing (touching, not overlapping).
+        assert_eq!(a.intersection(&b).intervals(), &[(3, 5)]);
+    }
+
+    #[test]
+    fn difference_carves_out_the_overlapping_middle() {
+        let a = TimeIntervalSet::merged(vec![(0, 10)]);
+        let b = TimeIntervalSet::merged(vec![(3, 6)]);
+        assert_eq!(a.difference(&b).intervals(), &[(0, 3), (6, 10)]);
+    }
+
+    #[test]
+    fn difference_against_a_superset_is_empty() {
+        let a = TimeIntervalSet::merged(vec![(3, 6)]);
+        let b = TimeIntervalSet::merged(vec![(0, 10)]);
+        assert!(a.difference(&b).is_empty());
+    }
+}
+
+impl FromIterator<Interval> for TimeIntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        Self::merged(iter)
+    }
+}
+
+impl IntoIterator for TimeIntervalSet {
+    type Item = Interval;
+    type IntoIter = std::vec::IntoIter<Interval>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.intervals.into_iter()
+    }
+}
@@ -1,4 +1,5 @@
 use crate::{
+    algorithms::{dfs_traversal::dfs_with_cycles_from_ops, metrics::adjacency_matrix::AdjacencyMatrix},
     core::{
         entities::{edges::edge_ref::EdgeRef, nodes::node_ref::NodeRef, LayerIds, EID, VID},
         Direction,
@@ -11,6 +12,17 @@ use crate::{
 
 /// The GraphViewInternalOps trait provides a set of methods to query a directed graph
 /// represented by the raphtory_core::tgraph::TGraph struct.
+///
+/// Mostly structural (existence, degree, neighbours, edge references), each taking an explicit
+/// `LayerIds`/`EdgeFilter` (and, for the directed queries, a `Direction`), but [`edge_history`]
+/// also exposes an edge's exploded activation times, for the callers below that need to walk the
+/// graph and read per-edge timestamps in the same pass without going through the view layer:
+///
+/// - [`Dot`](crate::db::graph::dot::Dot) (temporal DOT annotations)
+/// - [`earliest_arrival_times_from_ops`](crate::algorithms::pathing::earliest_arrival::earliest_arrival_times_from_ops)
+///   (per-edge activation times during the relaxation step)
+///
+/// [`edge_history`]: GraphOps::edge_history
 pub trait GraphOps<'graph>: Send + Sync {
     /// Check if a node exists and returns internal reference.
     fn internal_node_ref(
@@ -133,6 +145,19 @@ pub trait GraphOps<'graph>: Send + Sync {
         filter: Option<&EdgeFilter>,
     ) -> BoxedLIter<'graph, EdgeRef>;
 
+    /// Returns the exploded activation times of an edge, i.e. every timestamp at which it was
+    /// added, restricted to `layers` and `filter`. An edge with no activations (e.g. a purely
+    /// structural/static edge) returns an empty vector.
+    /// # Arguments
+    ///
+    /// * `edge` - The edge to read activation times for.
+    fn edge_history(
+        &self,
+        edge: EdgeRef,
+        layers: &LayerIds,
+        filter: Option<&EdgeFilter>,
+    ) -> Vec<i64>;
+
     /// Returns an iterator over the neighbors of a given node in a given direction.
     ///
     /// # Arguments
@@ -150,6 +175,33 @@ pub trait GraphOps<'graph>: Send + Sync {
         layers: LayerIds,
         filter: Option<&EdgeFilter>,
     ) -> BoxedLIter<'graph, VID>;
+
+    /// Materializes a dense, bit-packed `n x n` adjacency snapshot of this view in one pass:
+    /// allocates a buffer sized off `node_refs(...)`, then sets bit `dst` of row `src` for every
+    /// edge drained from `edge_refs(...)`. Each row is padded to whole `u64` words
+    /// (`ceil(n/64)` of them), so the bit index is `src * ceil(n/64) * 64 + dst` rather than
+    /// `src * n + dst`, and the buffer is not a tightly packed `n * n` bits. Dense-graph algorithms
+    /// (isomorphism checks, triangle counting, motif detection) that need many `is_adjacent`
+    /// checks build this once instead of paying the layered/filtered dispatch per query. See
+    /// [`AdjacencyMatrix`].
+    fn adjacency_matrix(&self, layers: LayerIds, filter: Option<&EdgeFilter>) -> AdjacencyMatrix {
+        AdjacencyMatrix::build_from_ops(self, layers, filter)
+    }
+
+    /// Depth-first traversal from `start` using the three-colour scheme (undiscovered / on the
+    /// current stack / finished), safe on cyclic (including temporal) graphs: a neighbour still
+    /// on the stack is reported as a back edge instead of being re-descended into. Walks
+    /// `neighbours(v, Direction::OUT, layers, filter)` so only edges live in `layers`/`filter` are
+    /// traversed. Returns the discovery order together with every back edge found; see
+    /// [`crate::algorithms::dfs_traversal`] for the view-layer convenience wrapper.
+    fn dfs_with_cycles(
+        &self,
+        start: VID,
+        layers: &LayerIds,
+        filter: Option<&EdgeFilter>,
+    ) -> (Vec<VID>, Vec<(VID, VID)>) {
+        dfs_with_cycles_from_ops(self, start, layers, filter)
+    }
 }
 
 pub trait InheritGraphOps: Base + Send + Sync {}
@@ -237,6 +289,16 @@ where
         self.base().edge_ref(src, dst, layer, filter)
     }
 
+    #[inline]
+    fn edge_history(
+        &self,
+        edge: EdgeRef,
+        layers: &LayerIds,
+        filter: Option<&EdgeFilter>,
+    ) -> Vec<i64> {
+        self.base().edge_history(edge, layers, filter)
+    }
+
     #[inline]
     fn node_refs(&self, layers: LayerIds, filter: Option<&EdgeFilter>) -> BoxedLIter<'graph, VID> {
         self.base().node_refs(layers, filter)
@@ -272,4 +334,19 @@ where
     ) -> BoxedLIter<'graph, VID> {
         self.base().neighbours(v, d, layers, filter)
     }
+
+    #[inline]
+    fn adjacency_matrix(&self, layers: LayerIds, filter: Option<&EdgeFilter>) -> AdjacencyMatrix {
+        self.base().adjacency_matrix(layers, filter)
+    }
+
+    #[inline]
+    fn dfs_with_cycles(
+        &self,
+        start: VID,
+        layers: &LayerIds,
+        filter: Option<&EdgeFilter>,
+    ) -> (Vec<VID>, Vec<(VID, VID)>) {
+        self.base().dfs_with_cycles(start, layers, filter)
+    }
 }
@@ -4,8 +4,9 @@ use crate::{
         api::view::internal::{OneHopFilter, TimeSemantics},
         graph::views::window_graph::WindowedGraph,
     },
+    prelude::{EdgeViewOps, GraphViewOps},
 };
-use std::marker::PhantomData;
+use std::{marker::PhantomData, ops::Bound};
 
 /// Trait defining time query operations
 pub trait TimeOps<'graph> {
@@ -28,6 +29,36 @@ pub trait TimeOps<'graph> {
     /// Create a view including all events between `start` (inclusive) and `end` (exclusive)
     fn window<T: IntoTime>(&self, start: T, end: T) -> Self::WindowedViewType;
 
+    /// Create a view from `start`/`end` bounds expressed as [`Bound`], mirroring
+    /// `std::collections::Bound` range semantics instead of always-half-open `[start, end)`.
+    ///
+    /// Each endpoint is normalized against the inclusive integer timeline before being handed to
+    /// [`TimeOps::window`]: `Included(t)` stays at `t`, `Excluded(t)` shifts by one towards the
+    /// inside of the range, and `Unbounded` maps to `i64::MIN`/`i64::MAX`. This lets callers
+    /// express "up to and including `t`" or "strictly after `t`" without manual `+1` fudging.
+    ///
+    /// ```rust
+    /// use raphtory::prelude::*;
+    /// use std::ops::Bound;
+    /// let g = Graph::new();
+    /// g.add_edge(6, 1, 2, NO_PROPS, None).unwrap();
+    /// // equivalent to window(6, 11)
+    /// g.window_with_bounds(Bound::Included(6), Bound::Included(10));
+    /// ```
+    fn window_with_bounds(&self, start: Bound<i64>, end: Bound<i64>) -> Self::WindowedViewType {
+        let start = match start {
+            Bound::Included(t) => t,
+            Bound::Excluded(t) => t.saturating_add(1),
+            Bound::Unbounded => i64::MIN,
+        };
+        let end = match end {
+            Bound::Included(t) => t.saturating_add(1),
+            Bound::Excluded(t) => t,
+            Bound::Unbounded => i64::MAX,
+        };
+        self.window(start, end)
+    }
+
     /// Create a view that only includes events at `time`
     fn at<T: IntoTime>(&self, time: T) -> Self::WindowedViewType {
         let start = time.into_time();
@@ -102,6 +133,23 @@ pub trait TimeOps<'graph> {
             _ => Ok(WindowSet::empty(parent)),
         }
     }
+
+    /// Creates an `EventCountWindowSet` where each window covers exactly `n` consecutive
+    /// temporal events (edge updates) in timestamp order, rather than a fixed span of time.
+    ///
+    /// The final window may hold fewer than `n` events if the total event count does not divide
+    /// evenly by `n`, and a window may hold *more* than `n` events if a run of events sharing the
+    /// same timestamp would otherwise straddle a chunk boundary - see [`EventCountWindowSet::new`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is `0`: there is no meaningful window of zero events to build.
+    fn rolling_count(&self, n: usize) -> EventCountWindowSet<'graph, Self>
+    where
+        Self: GraphViewOps<'graph> + Clone + 'graph,
+    {
+        EventCountWindowSet::new(self.clone(), n)
+    }
 }
 
 impl<'graph, V: OneHopFilter<'graph> + 'graph> TimeOps<'graph> for V {
@@ -128,21 +176,45 @@ impl<'graph, V: OneHopFilter<'graph> + 'graph> TimeOps<'graph> for V {
 pub struct WindowSet<'graph, T> {
     view: T,
     cursor: i64,
+    back_cursor: i64,
     end: i64,
     step: Interval,
     window: Option<Interval>,
+    len: usize,
     _marker: PhantomData<&'graph T>,
 }
 
 impl<'graph, T: TimeOps<'graph> + Clone + 'graph> WindowSet<'graph, T> {
     fn new(view: T, start: i64, end: i64, step: Interval, window: Option<Interval>) -> Self {
         let cursor_start = start + step;
+        // Walk the same `cursor + step` arithmetic `next`/`next_back` use to find how many
+        // windows there are and the last aligned boundary `< end + step` (which can lie past
+        // `end`, exactly as the last window `next` yields does), so both directions and
+        // `ExactSizeIterator::len` stay in lockstep without re-deriving counts from scratch.
+        let mut len = 0usize;
+        let mut back_cursor = cursor_start;
+        if cursor_start < end + step {
+            len = 1;
+            let mut probe = cursor_start;
+            loop {
+                let next = probe + step;
+                if next < end + step {
+                    probe = next;
+                    len += 1;
+                } else {
+                    break;
+                }
+            }
+            back_cursor = probe;
+        }
         Self {
             view,
             cursor: cursor_start,
+            back_cursor,
             end,
             step,
             window,
+            len,
             _marker: PhantomData,
         }
     }
@@ -188,26 +260,133 @@ impl<'graph, T: TimeOps<'graph> + Clone + 'graph> Iterator for TimeIndex<'graph,
             }
         })
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.windowset.size_hint()
+    }
+}
+
+impl<'graph, T: TimeOps<'graph> + Clone + 'graph> ExactSizeIterator for TimeIndex<'graph, T> {
+    fn len(&self) -> usize {
+        self.windowset.len()
+    }
+}
+
+impl<'graph, T: TimeOps<'graph> + Clone + 'graph> DoubleEndedIterator for TimeIndex<'graph, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let center = self.center;
+        self.windowset.next_back().map(move |view| {
+            if center {
+                view.start().unwrap() + ((view.end().unwrap() - view.start().unwrap()) / 2)
+            } else {
+                view.end().unwrap() - 1
+            }
+        })
+    }
 }
 
 impl<'graph, T: TimeOps<'graph> + Clone + 'graph> Iterator for WindowSet<'graph, T> {
     type Item = T::WindowedViewType;
     fn next(&mut self) -> Option<Self::Item> {
-        if self.cursor < self.end + self.step {
-            let window_end = self.cursor;
-            let window_start = self
-                .window
-                .map(|w| window_end - w)
-                .unwrap_or(self.view.start().unwrap_or(window_end));
-            let window = self.view.window(window_start, window_end);
-            self.cursor = self.cursor + self.step;
-            Some(window)
-        } else {
-            None
+        if self.len == 0 {
+            return None;
+        }
+        let window_end = self.cursor;
+        let window_start = self
+            .window
+            .map(|w| window_end - w)
+            .unwrap_or(self.view.start().unwrap_or(window_end));
+        let window = self.view.window(window_start, window_end);
+        self.cursor = self.cursor + self.step;
+        self.len -= 1;
+        Some(window)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<'graph, T: TimeOps<'graph> + Clone + 'graph> ExactSizeIterator for WindowSet<'graph, T> {
+    fn len(&self) -> usize {
+        self.len
+    }
+}
+
+impl<'graph, T: TimeOps<'graph> + Clone + 'graph> DoubleEndedIterator for WindowSet<'graph, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let window_end = self.back_cursor;
+        let window_start = self
+            .window
+            .map(|w| window_end - w)
+            .unwrap_or(self.view.start().unwrap_or(window_end));
+        let window = self.view.window(window_start, window_end);
+        self.back_cursor = self.back_cursor - self.step;
+        self.len -= 1;
+        Some(window)
+    }
+}
+
+/// An iterator over tumbling windows that each contain `n` consecutive temporal events (edge
+/// updates), rather than a fixed span of time; the last window may hold fewer, and a window holds
+/// more when a run of same-timestamp events is kept whole (see [`EventCountWindowSet::new`]). Window boundaries are computed once, up
+/// front, by collecting every edge-update timestamp in the view, sorting it, and chunking that
+/// stream into groups of `n`; each window is then materialized lazily via [`TimeOps::window`] as
+/// the iterator is driven, exactly like [`WindowSet`].
+pub struct EventCountWindowSet<'graph, T> {
+    view: T,
+    bounds: std::vec::IntoIter<(i64, i64)>,
+    _marker: PhantomData<&'graph T>,
+}
+
+impl<'graph, T: GraphViewOps<'graph> + Clone + 'graph> EventCountWindowSet<'graph, T> {
+    /// A fixed-size chunk of the sorted timestamps is turned into a `[chunk[0], chunk.last() + 1)`
+    /// time-range window - but a window is a *time range*, not a record of which original events
+    /// were counted into it. If a chunk boundary landed in the middle of a run of events sharing
+    /// the exact same timestamp, the two adjacent chunks would produce the identical time range
+    /// `[t, t + 1)`, and since each is materialized independently via [`TimeOps::window`], both
+    /// windows would then each contain the *whole* run, double-counting every event in it. So
+    /// instead of chunking at a fixed size, a chunk boundary is pushed past the end of any
+    /// same-timestamp run it would otherwise fall inside - the window that results can hold more
+    /// than `n` events when that happens, trading "`n` events per window" for "no event is ever
+    /// double-counted".
+    fn new(view: T, n: usize) -> Self {
+        assert!(n > 0, "rolling_count: n must be greater than 0");
+
+        let mut timestamps: Vec<i64> = view.edges().flat_map(|e| e.history()).collect();
+        timestamps.sort_unstable();
+
+        let mut bounds: Vec<(i64, i64)> = Vec::new();
+        let mut i = 0;
+        while i < timestamps.len() {
+            let mut j = (i + n).min(timestamps.len());
+            while j < timestamps.len() && timestamps[j] == timestamps[j - 1] {
+                j += 1;
+            }
+            bounds.push((timestamps[i], timestamps[j - 1] + 1));
+            i = j;
+        }
+
+        Self {
+            view,
+            bounds: bounds.into_iter(),
+            _marker: PhantomData,
         }
     }
 }
 
+impl<'graph, T: GraphViewOps<'graph> + Clone + 'graph> Iterator for EventCountWindowSet<'graph, T> {
+    type Item = T::WindowedViewType;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (start, end) = self.bounds.next()?;
+        Some(self.view.window(start, end))
+    }
+}
+
 #[cfg(test)]
 mod time_tests {
     use crate::{
@@ -351,4 +530,70 @@ mod time_tests {
         ];
         assert_bounds(windows, expected);
     }
+
+    #[test]
+    fn rolling_count() {
+        let g = Graph::new();
+        for t in [1, 2, 3, 5, 8, 13] {
+            g.add_edge(t, 0, 1, NO_PROPS, None).unwrap();
+        }
+        let windows: Vec<(i64, i64)> = g
+            .rolling_count(2)
+            .map(|w| (w.start().unwrap(), w.end().unwrap()))
+            .collect();
+        assert_eq!(windows, vec![(1, 3), (3, 6), (8, 14)]);
+    }
+
+    #[test]
+    fn rolling_count_merges_a_chunk_boundary_that_would_split_same_timestamp_events() {
+        let g = Graph::new();
+        // Four activations at t = 5 (two edges, each updated twice) straddle what would be a
+        // chunk-of-2 boundary right in the middle of them. Splitting there would give two windows
+        // both equal to [5, 6), each independently re-counting all four activations.
+        g.add_edge(5, 0, 1, NO_PROPS, None).unwrap();
+        g.add_edge(5, 0, 1, NO_PROPS, None).unwrap();
+        g.add_edge(5, 2, 3, NO_PROPS, None).unwrap();
+        g.add_edge(5, 2, 3, NO_PROPS, None).unwrap();
+        g.add_edge(9, 0, 1, NO_PROPS, None).unwrap();
+
+        let windows: Vec<(i64, i64)> = g
+            .rolling_count(2)
+            .map(|w| (w.start().unwrap(), w.end().unwrap()))
+            .collect();
+        // The same-timestamp run at t = 5 is kept in a single window instead of being split, even
+        // though that means the window holds more than 2 events; the trailing t = 9 event gets
+        // its own (undersized) window.
+        assert_eq!(windows, vec![(5, 6), (9, 10)]);
+
+        let total_activations: usize = g
+            .rolling_count(2)
+            .map(|w| w.edges().iter().map(|e| e.history().len()).sum::<usize>())
+            .sum();
+        assert_eq!(total_activations, 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "n must be greater than 0")]
+    fn rolling_count_rejects_a_zero_sized_window() {
+        let g = Graph::new();
+        g.add_edge(1, 0, 1, NO_PROPS, None).unwrap();
+        let _ = g.rolling_count(0);
+    }
+
+    #[test]
+    fn rolling_is_double_ended_and_exact_size() {
+        let g = graph_with_timeline(1, 7);
+        let windows = g.rolling(2, None).unwrap();
+        assert_eq!(windows.len(), 3);
+
+        let mut windows = g.rolling(2, None).unwrap();
+        let first = windows.next().map(|w| (w.start().unwrap(), w.end().unwrap()));
+        let last = windows.next_back().map(|w| (w.start().unwrap(), w.end().unwrap()));
+        let middle = windows.next().map(|w| (w.start().unwrap(), w.end().unwrap()));
+        assert_eq!(first, Some((1, 3)));
+        assert_eq!(last, Some((5, 7)));
+        assert_eq!(middle, Some((3, 5)));
+        assert_eq!(windows.next(), None);
+        assert_eq!(windows.next_back(), None);
+    }
 }
@@ -0,0 +1,401 @@
+//! Graphviz DOT export for graph views.
+//!
+//! Mirrors the shape of petgraph's `Dot::with_config`: a `Dot` writer wraps a graph view and a
+//! small set of `Config` flags, and callers can supply closures that map a [`VertexView`]/
+//! [`EdgeView`] to the label text that should appear in the generated `label="..."` attribute.
+//! Because the writer operates over any `GraphViewOps` value, it automatically honors whatever
+//! window/layer the view was built with, e.g. `g.window(0, 10).layer("l1").to_dot(...)`.
+//!
+//! Temporal metadata and per-layer structure can also be rendered as edge attributes:
+//! [`Config::EdgeTemporal`] adds the edge's activation timestamps within the view (or, when there
+//! are more than 8 of them, its earliest/latest time), [`Config::EdgeLayer`] adds its layer name, and
+//! [`Config::CollapseLayers`] merges same-`(src, dst)` parallel edges from different layers into
+//! a single styled edge instead of emitting one DOT edge per layer.
+//!
+//! Vertex/edge iteration and the `label = "..."` text itself stay on `GraphViewOps`/`EdgeViewOps`/
+//! `VertexViewOps`, since closures here are written in terms of those view types. But
+//! [`Config::EdgeTemporal`]'s activation-time read is built directly on [`GraphOps::edge_history`]
+//! (`db/api/view/internal/graph_ops.rs`) via `edge_ref`, rather than the view layer's
+//! `EdgeViewOps::history`: it is the one piece of what this exporter renders that `GraphOps` now
+//! has an equivalent for. `layer_name`/`properties` (behind [`Config::EdgeLayer`] and
+//! `with_edge_properties`) still have no `GraphOps` equivalent — layer naming and property storage
+//! are view-layer concerns, not structural ones — so those stay as `EdgeViewOps` calls.
+use crate::{
+    db::{
+        api::view::internal::GraphOps,
+        graph::{edge::EdgeView, vertex::VertexView},
+    },
+    prelude::{EdgeViewOps, GraphViewOps, VertexViewOps},
+};
+use std::{
+    collections::HashMap,
+    fmt::{self, Display, Formatter},
+};
+
+/// Configuration flags controlling what `Dot` renders, analogous to petgraph's `dot::Config`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Config {
+    /// Do not print labels on vertices.
+    NodeNoLabel,
+    /// Do not print labels on edges.
+    EdgeNoLabel,
+    /// Annotate each edge with the list of its activation timestamps inside the view (`time`)
+    /// when there are at most 8 of them, and otherwise with its earliest/latest activation time.
+    /// The choice depends only on that count, not on whether the view is windowed.
+    EdgeTemporal,
+    /// Annotate each edge with the name of the layer it belongs to.
+    EdgeLayer,
+    /// Merge parallel edges that only differ by layer into a single edge, styled `style = bold`
+    /// and annotated with the full set of collapsed layer names.
+    CollapseLayers,
+}
+
+/// A Graphviz DOT writer for a `GraphViewOps` graph (or any windowed/layered subview of one).
+pub struct Dot<'graph, G, NF, EF> {
+    graph: &'graph G,
+    config: Vec<Config>,
+    node_label: NF,
+    edge_label: EF,
+    edge_properties: Vec<String>,
+}
+
+impl<'graph, G> Dot<'graph, G, fn(&VertexView<G>) -> String, fn(&EdgeView<G, G>) -> String>
+where
+    G: GraphViewOps,
+{
+    /// Creates a `Dot` writer using the vertex name and a `src -> dst` summary as default labels.
+    pub fn new(graph: &'graph G) -> Self {
+        Self {
+            graph,
+            config: Vec::new(),
+            node_label: |v| v.name(),
+            edge_label: |e| format!("{} -> {}", e.src().name(), e.dst().name()),
+            edge_properties: Vec::new(),
+        }
+    }
+}
+
+impl<'graph, G, NF, EF> Dot<'graph, G, NF, EF>
+where
+    G: GraphViewOps,
+    NF: Fn(&VertexView<G>) -> String,
+    EF: Fn(&EdgeView<G, G>) -> String,
+{
+    /// Creates a `Dot` writer with explicit config flags and label closures, mirroring
+    /// petgraph's `Dot::with_config`.
+    pub fn with_config(graph: &'graph G, config: &[Config], node_label: NF, edge_label: EF) -> Self {
+        Self {
+            graph,
+            config: config.to_vec(),
+            node_label,
+            edge_label,
+            edge_properties: Vec::new(),
+        }
+    }
+
+    /// Additionally renders the named edge properties as `prop_name = "value"` DOT attributes,
+    /// skipping any property an edge does not carry.
+    pub fn with_edge_properties(mut self, properties: &[&str]) -> Self {
+        self.edge_properties = properties.iter().map(|p| p.to_string()).collect();
+        self
+    }
+
+    fn show_node_labels(&self) -> bool {
+        !self.config.contains(&Config::NodeNoLabel)
+    }
+
+    fn show_edge_labels(&self) -> bool {
+        !self.config.contains(&Config::EdgeNoLabel)
+    }
+
+    fn show_temporal(&self) -> bool {
+        self.config.contains(&Config::EdgeTemporal)
+    }
+
+    fn show_layer(&self) -> bool {
+        self.config.contains(&Config::EdgeLayer)
+    }
+
+    fn collapse_layers(&self) -> bool {
+        self.config.contains(&Config::CollapseLayers)
+    }
+
+    /// Extra `attr = "value"` attributes for a single edge: temporal metadata, layer name and
+    /// the selected edge properties, per the active `Config` flags.
+    fn edge_attrs(&self, edge: &EdgeView<G, G>) -> Vec<(String, String)> {
+        let mut attrs = Vec::new();
+        if self.show_temporal() {
+            let layers = self.graph.layer_ids();
+            let filter = self.graph.edge_filter();
+            let history = self
+                .graph
+                .edge_ref(edge.src().vertex, edge.dst().vertex, &layers, filter)
+                .map(|e_ref| self.graph.edge_history(e_ref, &layers, filter))
+                .unwrap_or_default();
+            if history.len() <= 8 {
+                attrs.push((
+                    "time".to_string(),
+                    format!("{:?}", history).replace('"', "'"),
+                ));
+            } else if let (Some(&earliest), Some(&latest)) =
+                (history.iter().min(), history.iter().max())
+            {
+                attrs.push(("earliest_time".to_string(), earliest.to_string()));
+                attrs.push(("latest_time".to_string(), latest.to_string()));
+            }
+        }
+        if self.show_layer() {
+            if let Some(layer) = edge.layer_name() {
+                attrs.push(("layer".to_string(), layer));
+            }
+        }
+        for prop in &self.edge_properties {
+            if let Some(value) = edge.properties().get(prop) {
+                attrs.push((prop.clone(), value.to_string()));
+            }
+        }
+        attrs
+    }
+}
+
+impl<'graph, G, NF, EF> Display for Dot<'graph, G, NF, EF>
+where
+    G: GraphViewOps,
+    NF: Fn(&VertexView<G>) -> String,
+    EF: Fn(&EdgeView<G, G>) -> String,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(f, "digraph {{")?;
+        for vertex in self.graph.vertices() {
+            if self.show_node_labels() {
+                writeln!(
+                    f,
+                    "    {} [ label = \"{}\" ]",
+                    vertex.id(),
+                    escape((self.node_label)(&vertex))
+                )?;
+            } else {
+                writeln!(f, "    {}", vertex.id())?;
+            }
+        }
+
+        if self.collapse_layers() {
+            let mut collapsed: HashMap<(u64, u64), (EdgeView<G, G>, Vec<String>)> = HashMap::new();
+            for edge in self.graph.edges() {
+                let key = (edge.src().id(), edge.dst().id());
+                let layer = edge.layer_name().unwrap_or_default();
+                collapsed
+                    .entry(key)
+                    .or_insert_with(|| (edge.clone(), Vec::new()))
+                    .1
+                    .push(layer);
+            }
+            for ((src, dst), (edge, layers)) in collapsed {
+                let mut attrs = self.edge_attrs(&edge);
+                attrs.retain(|(k, _)| k != "layer");
+                if !layers.is_empty() {
+                    attrs.push(("layers".to_string(), layers.join(",")));
+                }
+                if layers.len() > 1 {
+                    attrs.push(("style".to_string(), "bold".to_string()));
+                }
+                self.write_edge(f, src, dst, &edge, &attrs)?;
+            }
+        } else {
+            for edge in self.graph.edges() {
+                let attrs = self.edge_attrs(&edge);
+                self.write_edge(f, edge.src().id(), edge.dst().id(), &edge, &attrs)?;
+            }
+        }
+
+        writeln!(f, "}}")
+    }
+}
+
+impl<'graph, G, NF, EF> Dot<'graph, G, NF, EF>
+where
+    G: GraphViewOps,
+    NF: Fn(&VertexView<G>) -> String,
+    EF: Fn(&EdgeView<G, G>) -> String,
+{
+    fn write_edge(
+        &self,
+        f: &mut Formatter<'_>,
+        src: u64,
+        dst: u64,
+        edge: &EdgeView<G, G>,
+        attrs: &[(String, String)],
+    ) -> fmt::Result {
+        let mut all_attrs = Vec::new();
+        if self.show_edge_labels() {
+            all_attrs.push(("label".to_string(), escape((self.edge_label)(edge))));
+        }
+        for (k, v) in attrs {
+            all_attrs.push((k.clone(), escape(v.clone())));
+        }
+        if all_attrs.is_empty() {
+            writeln!(f, "    {} -> {}", src, dst)
+        } else {
+            let rendered = all_attrs
+                .iter()
+                .map(|(k, v)| format!("{} = \"{}\"", k, v))
+                .collect::<Vec<_>>()
+                .join(", ");
+            writeln!(f, "    {} -> {} [ {} ]", src, dst, rendered)
+        }
+    }
+}
+
+/// Escapes backslashes and double quotes so label text is safe to embed in a DOT `label="..."`
+/// attribute. Backslashes must be escaped first: escaping quotes before backslashes would double-
+/// escape the backslashes that `\"` just introduced, and would leave any backslash original to the
+/// label unescaped, letting it combine with the attribute's closing quote into `\"`.
+fn escape(label: String) -> String {
+    label.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Extension trait adding `to_dot` to any graph view, following the same pattern as
+/// `Graph::save_to_file`/`load_from_file`.
+pub trait DotFormat: GraphViewOps + Sized {
+    /// Renders this view as Graphviz DOT text using the default vertex-name/edge-summary labels.
+    fn to_dot(&self) -> String {
+        Dot::new(self).to_string()
+    }
+
+    /// Renders this view as Graphviz DOT text, using the given config flags and label closures
+    /// to control what is written for each vertex/edge.
+    fn to_dot_with<NF, EF>(&self, config: &[Config], node_label: NF, edge_label: EF) -> String
+    where
+        NF: Fn(&VertexView<Self>) -> String,
+        EF: Fn(&EdgeView<Self, Self>) -> String,
+    {
+        Dot::with_config(self, config, node_label, edge_label).to_string()
+    }
+}
+
+impl<G: GraphViewOps> DotFormat for G {}
+
+#[cfg(test)]
+mod test {
+    use super::{Config, Dot, DotFormat};
+    use crate::{
+        core::Prop,
+        db::{api::mutation::AdditionOps, graph::graph::Graph},
+        prelude::*,
+    };
+
+    fn line_graph() -> Graph {
+        let g = Graph::new();
+        g.add_edge(0, "a", "b", [("weight".to_string(), Prop::F64(2.0))], None)
+            .unwrap();
+        g
+    }
+
+    #[test]
+    fn to_dot_renders_default_vertex_and_edge_labels() {
+        let g = line_graph();
+        let dot = g.to_dot();
+        assert!(dot.starts_with("digraph {\n"));
+        assert!(dot.ends_with("}\n"));
+        assert!(dot.contains("label = \"a\""));
+        assert!(dot.contains("label = \"b\""));
+        assert!(dot.contains("label = \"a -> b\""));
+    }
+
+    #[test]
+    fn with_edge_properties_adds_the_requested_attribute() {
+        let g = line_graph();
+        let dot = Dot::new(&g).with_edge_properties(&["weight"]).to_string();
+        assert!(dot.contains("weight = \""));
+    }
+
+    #[test]
+    fn node_no_label_omits_the_label_attribute() {
+        let g = line_graph();
+        let dot = g.to_dot_with(&[Config::NodeNoLabel], |v| v.name(), |e| {
+            format!("{} -> {}", e.src().name(), e.dst().name())
+        });
+        assert!(!dot.contains("label = \"a\""));
+        assert!(dot.contains("label = \"a -> b\""));
+    }
+
+    #[test]
+    fn edge_temporal_lists_activation_times() {
+        let g = Graph::new();
+        g.add_edge(1, "a", "b", crate::prelude::NO_PROPS, None)
+            .unwrap();
+        g.add_edge(2, "a", "b", crate::prelude::NO_PROPS, None)
+            .unwrap();
+        let dot = g.to_dot_with(
+            &[Config::EdgeTemporal],
+            |v| v.name(),
+            |e| format!("{} -> {}", e.src().name(), e.dst().name()),
+        );
+        assert!(dot.contains("time = "));
+        assert!(dot.contains('1'));
+        assert!(dot.contains('2'));
+    }
+
+    #[test]
+    fn collapse_layers_merges_parallel_edges_into_one_bold_edge() {
+        let g = Graph::new();
+        g.add_edge(0, "a", "b", crate::prelude::NO_PROPS, Some("l1"))
+            .unwrap();
+        g.add_edge(0, "a", "b", crate::prelude::NO_PROPS, Some("l2"))
+            .unwrap();
+        let dot = g.to_dot_with(
+            &[Config::CollapseLayers],
+            |v| v.name(),
+            |e| format!("{} -> {}", e.src().name(), e.dst().name()),
+        );
+        // Exactly one "a -> b" edge line should be emitted despite the two layers.
+        assert_eq!(dot.matches("label = \"a -> b\"").count(), 1);
+        assert!(dot.contains("style = \"bold\""));
+        assert!(dot.contains("layers = "));
+    }
+
+    #[test]
+    fn escape_handles_a_label_with_both_a_backslash_and_a_quote() {
+        let g = line_graph();
+        // The raw label is: a \ b "  (a backslash followed later by a quote).
+        let raw_label = "a\\b\"";
+        let dot = g.to_dot_with(&[Config::NodeNoLabel], |_| "irrelevant".to_string(), |_| {
+            raw_label.to_string()
+        });
+        // Escaping the backslash first, then the quote, turns `a\b"` into `a\\b\"` - if the quote
+        // were escaped first instead, the original backslash would combine with it into `\"` and
+        // silently close the attribute early.
+        assert!(dot.contains("label = \"a\\\\b\\\"\""), "dot output was:\n{dot}");
+    }
+
+    #[test]
+    fn edge_temporal_respects_a_window_when_reading_activation_times_via_graph_ops() {
+        let g = Graph::new();
+        g.add_edge(1, "a", "b", crate::prelude::NO_PROPS, None)
+            .unwrap();
+        g.add_edge(5, "a", "b", crate::prelude::NO_PROPS, None)
+            .unwrap();
+        let windowed = g.window(0, 3);
+        let dot = windowed.to_dot_with(
+            &[Config::EdgeTemporal],
+            |v| v.name(),
+            |e| format!("{} -> {}", e.src().name(), e.dst().name()),
+        );
+        // Only the t = 1 activation is inside [0, 3); the GraphOps-backed read must honor the
+        // window rather than reporting both activations from the unwindowed base graph.
+        assert!(dot.contains("time = \"[1]\""));
+    }
+
+    #[test]
+    fn edge_layer_annotates_each_edge_with_its_layer_name() {
+        let g = Graph::new();
+        g.add_edge(0, "a", "b", crate::prelude::NO_PROPS, Some("l1"))
+            .unwrap();
+        let dot = g.to_dot_with(
+            &[Config::EdgeLayer],
+            |v| v.name(),
+            |e| format!("{} -> {}", e.src().name(), e.dst().name()),
+        );
+        assert!(dot.contains("layer = \"l1\""));
+    }
+}
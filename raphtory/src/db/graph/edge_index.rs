@@ -0,0 +1,112 @@
+//! # Edge Activity Index
+//!
+//! Wraps [`IntervalIndex`] as the backing store for windowed exploded-edge queries: one interval
+//! per edge activation, so `overlapping(a, b)` answers "which edges were active during
+//! `[a, b)`?" without scanning every edge and testing `active(t)` one timestamp at a time.
+use crate::{
+    core::utils::interval_index::IntervalIndex, db::api::view::StaticGraphViewOps,
+    prelude::EdgeViewOps,
+};
+
+/// A built index of an edge's activations as `[t, t + 1)` intervals labelled with `(src, dst)`.
+pub struct EdgeIntervalIndex {
+    index: IntervalIndex<(u64, u64)>,
+}
+
+impl EdgeIntervalIndex {
+    /// Builds the index from every edge activation visible in `graph`'s current window/layer
+    /// selection.
+    pub fn build<G: StaticGraphViewOps>(graph: &G) -> Self {
+        let mut intervals = Vec::new();
+        for edge in graph.edges() {
+            let src = edge.src().id();
+            let dst = edge.dst().id();
+            for t in edge.history() {
+                intervals.push((t, t + 1, (src, dst)));
+            }
+        }
+        Self {
+            index: IntervalIndex::build(intervals),
+        }
+    }
+
+    /// Returns the `(src, dst)` pairs of every edge activation overlapping `[start, end)`.
+    pub fn overlapping(&self, start: i64, end: i64) -> Vec<(u64, u64)> {
+        self.index.query(start, end).into_iter().copied().collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.index.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.index.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::EdgeIntervalIndex;
+    use crate::{
+        db::{api::mutation::AdditionOps, graph::graph::Graph},
+        prelude::*,
+    };
+    use std::collections::HashSet;
+
+    fn two_edges_graph() -> Graph {
+        let g = Graph::new();
+        g.add_edge(0, "a", "b", NO_PROPS, None).unwrap();
+        g.add_edge(5, "a", "b", NO_PROPS, None).unwrap();
+        g.add_edge(5, "c", "d", NO_PROPS, None).unwrap();
+        g
+    }
+
+    #[test]
+    fn overlapping_finds_every_activation_touching_the_window() {
+        let g = two_edges_graph();
+        let index = EdgeIntervalIndex::build(&g);
+        let a = g.node("a").unwrap().id();
+        let b = g.node("b").unwrap().id();
+        let c = g.node("c").unwrap().id();
+        let d = g.node("d").unwrap().id();
+
+        let hits: HashSet<(u64, u64)> = index.overlapping(5, 6).into_iter().collect();
+        assert_eq!(hits, HashSet::from([(a, b), (c, d)]));
+
+        // Each activation is modelled as the half-open interval [t, t + 1), so a window that ends
+        // exactly at t = 0 must not see the activation that starts there.
+        assert!(index.overlapping(0, 0).is_empty());
+        assert!(!index.overlapping(0, 1).is_empty());
+    }
+
+    #[test]
+    fn len_counts_activations_not_distinct_edges() {
+        let g = two_edges_graph();
+        let index = EdgeIntervalIndex::build(&g);
+        assert_eq!(index.len(), 3);
+        assert!(!index.is_empty());
+
+        let empty = EdgeIntervalIndex::build(&Graph::new());
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn finds_activations_at_a_non_perfect_sized_index() {
+        // Five activations, one per edge, is not a "2^L - 1" size - this is a regression test for
+        // the underlying IntervalIndex, exercised through the wrapper that actually uses it.
+        let g = Graph::new();
+        g.add_edge(0, "a", "b", NO_PROPS, None).unwrap();
+        g.add_edge(1, "b", "c", NO_PROPS, None).unwrap();
+        g.add_edge(2, "c", "d", NO_PROPS, None).unwrap();
+        g.add_edge(3, "d", "e", NO_PROPS, None).unwrap();
+        g.add_edge(100, "x", "y", NO_PROPS, None).unwrap();
+
+        let index = EdgeIntervalIndex::build(&g);
+        assert_eq!(index.len(), 5);
+
+        let x = g.node("x").unwrap().id();
+        let y = g.node("y").unwrap().id();
+        let hits: HashSet<(u64, u64)> = index.overlapping(100, 101).into_iter().collect();
+        assert_eq!(hits, HashSet::from([(x, y)]));
+    }
+}
@@ -0,0 +1,498 @@
+//! # Property Filter DSL
+//!
+//! A small string expression language so callers can write
+//! `vertex.matches_filter("weight > 10 && layer == 'btc'")` instead of hand-writing
+//! `.filter_map(|(_, prop)| prop.into_u64())` closures. An expression is tokenized into
+//! identifiers (property names), literals (int/float/string/bool), comparison operators
+//! (`==`, `!=`, `<`, `<=`, `>`, `>=`) and boolean connectives (`&&`, `||`, `!`), then parsed with
+//! precedence climbing: `||` binds loosest, then `&&`, then comparisons, all left-associative.
+//! Identifiers are resolved against a vertex/edge's merged properties (temporal-latest falling
+//! back to constant) at evaluation time; a missing property makes the whole predicate `false`
+//! (even under `!` or `||`),
+//! while comparing incompatible `Prop` variants is a [`GraphError`].
+//!
+//! [`FilterOps::matches_filter`] checks a single vertex or edge; [`FilterIterableOps::filter`]
+//! runs the same expression over a whole [`Vertices`]/[`Edges`] iterable, e.g.
+//! `g.vertices().filter("weight > 10 && layer == 'btc'")`.
+use crate::{
+    core::{utils::errors::GraphError, Prop},
+    db::graph::{edge::EdgeView, edges::Edges, vertex::VertexView, vertices::Vertices},
+    prelude::GraphViewOps,
+};
+use std::cmp::Ordering;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Ident(String),
+    Int(i64),
+    Float(f64),
+    Str(String),
+    Bool(bool),
+    Op(String),
+    LParen,
+    RParen,
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, GraphError> {
+    let chars: Vec<char> = input.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            _ if c.is_whitespace() => i += 1,
+            '(' => {
+                tokens.push(Token::LParen);
+                i += 1;
+            }
+            ')' => {
+                tokens.push(Token::RParen);
+                i += 1;
+            }
+            '\'' | '"' => {
+                let quote = c;
+                i += 1;
+                let start = i;
+                while i < chars.len() && chars[i] != quote {
+                    i += 1;
+                }
+                if i >= chars.len() {
+                    return Err(GraphError::FilterError(
+                        "unterminated string literal".to_string(),
+                    ));
+                }
+                tokens.push(Token::Str(chars[start..i].iter().collect()));
+                i += 1;
+            }
+            '&' if chars.get(i + 1) == Some(&'&') => {
+                tokens.push(Token::Op("&&".to_string()));
+                i += 2;
+            }
+            '|' if chars.get(i + 1) == Some(&'|') => {
+                tokens.push(Token::Op("||".to_string()));
+                i += 2;
+            }
+            '=' if chars.get(i + 1) == Some(&'=') => {
+                tokens.push(Token::Op("==".to_string()));
+                i += 2;
+            }
+            '!' if chars.get(i + 1) == Some(&'=') => {
+                tokens.push(Token::Op("!=".to_string()));
+                i += 2;
+            }
+            '<' if chars.get(i + 1) == Some(&'=') => {
+                tokens.push(Token::Op("<=".to_string()));
+                i += 2;
+            }
+            '>' if chars.get(i + 1) == Some(&'=') => {
+                tokens.push(Token::Op(">=".to_string()));
+                i += 2;
+            }
+            '<' | '>' | '!' => {
+                tokens.push(Token::Op(c.to_string()));
+                i += 1;
+            }
+            _ if c.is_ascii_digit() => {
+                let start = i;
+                let mut is_float = false;
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    is_float |= chars[i] == '.';
+                    i += 1;
+                }
+                let text: String = chars[start..i].iter().collect();
+                if is_float {
+                    tokens.push(Token::Float(text.parse().map_err(|_| {
+                        GraphError::FilterError(format!("invalid number '{text}'"))
+                    })?));
+                } else {
+                    tokens.push(Token::Int(text.parse().map_err(|_| {
+                        GraphError::FilterError(format!("invalid number '{text}'"))
+                    })?));
+                }
+            }
+            _ if c.is_alphabetic() || c == '_' => {
+                let start = i;
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                let text: String = chars[start..i].iter().collect();
+                tokens.push(match text.as_str() {
+                    "true" => Token::Bool(true),
+                    "false" => Token::Bool(false),
+                    _ => Token::Ident(text),
+                });
+            }
+            _ => {
+                return Err(GraphError::FilterError(format!(
+                    "unexpected character '{c}'"
+                )))
+            }
+        }
+    }
+    Ok(tokens)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum CompareOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+/// The parsed form of a filter expression, evaluated via [`Expr::eval`].
+#[derive(Debug, Clone)]
+pub enum Expr {
+    Literal(Prop),
+    Ident(String),
+    Not(Box<Expr>),
+    And(Box<Expr>, Box<Expr>),
+    Or(Box<Expr>, Box<Expr>),
+    Compare(CompareOp, Box<Expr>, Box<Expr>),
+}
+
+fn binding_power(op: &str) -> u8 {
+    match op {
+        "||" => 1,
+        "&&" => 2,
+        "==" | "!=" | "<" | "<=" | ">" | ">=" => 3,
+        _ => 0,
+    }
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&Token> {
+        self.tokens.get(self.pos)
+    }
+
+    fn bump(&mut self) -> Option<Token> {
+        let t = self.tokens.get(self.pos).cloned();
+        self.pos += 1;
+        t
+    }
+
+    /// Precedence climbing: parse a primary, then fold in binary operators whose binding power
+    /// meets `min_bp`, recursing with `bp + 1` on the right-hand side for left-associativity.
+    fn parse_expr(&mut self, min_bp: u8) -> Result<Expr, GraphError> {
+        let mut lhs = self.parse_primary()?;
+        loop {
+            let op = match self.peek() {
+                Some(Token::Op(o)) if binding_power(o) > 0 => o.clone(),
+                _ => break,
+            };
+            let bp = binding_power(&op);
+            if bp < min_bp {
+                break;
+            }
+            self.pos += 1;
+            let rhs = self.parse_expr(bp + 1)?;
+            lhs = combine(&op, lhs, rhs)?;
+        }
+        Ok(lhs)
+    }
+
+    fn parse_primary(&mut self) -> Result<Expr, GraphError> {
+        match self.bump() {
+            Some(Token::Op(ref o)) if o == "!" => {
+                Ok(Expr::Not(Box::new(self.parse_expr(4)?)))
+            }
+            Some(Token::LParen) => {
+                let inner = self.parse_expr(1)?;
+                match self.bump() {
+                    Some(Token::RParen) => Ok(inner),
+                    other => Err(GraphError::FilterError(format!(
+                        "expected closing ')', found {other:?}"
+                    ))),
+                }
+            }
+            Some(Token::Ident(name)) => Ok(Expr::Ident(name)),
+            Some(Token::Int(v)) => Ok(Expr::Literal(Prop::I64(v))),
+            Some(Token::Float(v)) => Ok(Expr::Literal(Prop::F64(v))),
+            Some(Token::Str(v)) => Ok(Expr::Literal(Prop::Str(v.into()))),
+            Some(Token::Bool(v)) => Ok(Expr::Literal(Prop::Bool(v))),
+            other => Err(GraphError::FilterError(format!(
+                "unexpected token {other:?}"
+            ))),
+        }
+    }
+}
+
+fn combine(op: &str, lhs: Expr, rhs: Expr) -> Result<Expr, GraphError> {
+    Ok(match op {
+        "&&" => Expr::And(Box::new(lhs), Box::new(rhs)),
+        "||" => Expr::Or(Box::new(lhs), Box::new(rhs)),
+        "==" => Expr::Compare(CompareOp::Eq, Box::new(lhs), Box::new(rhs)),
+        "!=" => Expr::Compare(CompareOp::Ne, Box::new(lhs), Box::new(rhs)),
+        "<" => Expr::Compare(CompareOp::Lt, Box::new(lhs), Box::new(rhs)),
+        "<=" => Expr::Compare(CompareOp::Le, Box::new(lhs), Box::new(rhs)),
+        ">" => Expr::Compare(CompareOp::Gt, Box::new(lhs), Box::new(rhs)),
+        ">=" => Expr::Compare(CompareOp::Ge, Box::new(lhs), Box::new(rhs)),
+        _ => return Err(GraphError::FilterError(format!("unknown operator '{op}'"))),
+    })
+}
+
+/// Parses a filter expression, ready to be evaluated with [`Expr::eval`].
+pub fn parse_filter(input: &str) -> Result<Expr, GraphError> {
+    let tokens = tokenize(input)?;
+    let mut parser = Parser { tokens, pos: 0 };
+    let expr = parser.parse_expr(1)?;
+    if parser.pos != parser.tokens.len() {
+        return Err(GraphError::FilterError(
+            "unexpected trailing input".to_string(),
+        ));
+    }
+    Ok(expr)
+}
+
+fn compare(op: CompareOp, lhs: &Prop, rhs: &Prop) -> Result<bool, GraphError> {
+    let ordering = match (lhs, rhs) {
+        (Prop::Str(l), Prop::Str(r)) => l.as_ref().cmp(r.as_ref()),
+        (Prop::Bool(l), Prop::Bool(r)) => l.cmp(r),
+        _ => {
+            let (Some(l), Some(r)) = (lhs.clone().into_f64(), rhs.clone().into_f64()) else {
+                return Err(GraphError::FilterError(format!(
+                    "cannot compare {lhs:?} and {rhs:?}"
+                )));
+            };
+            l.partial_cmp(&r).ok_or_else(|| {
+                GraphError::FilterError(format!("cannot compare {lhs:?} and {rhs:?}"))
+            })?
+        }
+    };
+    Ok(match op {
+        CompareOp::Eq => ordering == Ordering::Equal,
+        CompareOp::Ne => ordering != Ordering::Equal,
+        CompareOp::Lt => ordering == Ordering::Less,
+        CompareOp::Le => ordering != Ordering::Greater,
+        CompareOp::Gt => ordering == Ordering::Greater,
+        CompareOp::Ge => ordering != Ordering::Less,
+    })
+}
+
+fn eval_value(
+    expr: &Expr,
+    resolve: &dyn Fn(&str) -> Option<Prop>,
+) -> Result<Option<Prop>, GraphError> {
+    match expr {
+        Expr::Literal(p) => Ok(Some(p.clone())),
+        Expr::Ident(name) => Ok(resolve(name)),
+        _ => Err(GraphError::FilterError(
+            "expected a value, found a boolean expression".to_string(),
+        )),
+    }
+}
+
+impl Expr {
+    /// Evaluates the expression against a vertex/edge, resolving identifiers with `resolve`. If
+    /// any property the expression names is missing, the whole predicate evaluates to `false`
+    /// rather than erroring, whatever `!`/`||` surround it.
+    pub fn eval(&self, resolve: &dyn Fn(&str) -> Option<Prop>) -> Result<bool, GraphError> {
+        if self.names_missing_property(resolve) {
+            return Ok(false);
+        }
+        match self {
+            Expr::Literal(p) => Ok(is_truthy(p)),
+            Expr::Ident(name) => Ok(resolve(name).as_ref().is_some_and(is_truthy)),
+            Expr::Not(e) => Ok(!e.eval(resolve)?),
+            Expr::And(l, r) => Ok(l.eval(resolve)? && r.eval(resolve)?),
+            Expr::Or(l, r) => Ok(l.eval(resolve)? || r.eval(resolve)?),
+            Expr::Compare(op, l, r) => {
+                let (Some(lv), Some(rv)) = (eval_value(l, resolve)?, eval_value(r, resolve)?)
+                else {
+                    return Ok(false);
+                };
+                compare(*op, &lv, &rv)
+            }
+        }
+    }
+
+    fn names_missing_property(&self, resolve: &dyn Fn(&str) -> Option<Prop>) -> bool {
+        match self {
+            Expr::Literal(_) => false,
+            Expr::Ident(name) => resolve(name).is_none(),
+            Expr::Not(e) => e.names_missing_property(resolve),
+            Expr::And(l, r) | Expr::Or(l, r) | Expr::Compare(_, l, r) => {
+                l.names_missing_property(resolve) || r.names_missing_property(resolve)
+            }
+        }
+    }
+}
+
+fn is_truthy(prop: &Prop) -> bool {
+    matches!(prop, Prop::Bool(true))
+}
+
+/// Evaluate a [`crate::db::graph::filter`] expression against a vertex's or edge's merged
+/// properties; see the module docs for the expression syntax.
+pub trait FilterOps {
+    fn matches_filter(&self, expr: &str) -> Result<bool, GraphError>;
+}
+
+impl<G: GraphViewOps> FilterOps for VertexView<G, G> {
+    fn matches_filter(&self, expr: &str) -> Result<bool, GraphError> {
+        use crate::prelude::VertexViewOps;
+        let ast = parse_filter(expr)?;
+        ast.eval(&|name| self.properties().get(name))
+    }
+}
+
+impl<G: GraphViewOps> FilterOps for EdgeView<G, G> {
+    fn matches_filter(&self, expr: &str) -> Result<bool, GraphError> {
+        use crate::prelude::EdgeViewOps;
+        let ast = parse_filter(expr)?;
+        ast.eval(&|name| self.properties().get(name))
+    }
+}
+
+/// Evaluate a [`crate::db::graph::filter`] expression against every item of a vertex/edge
+/// iterable, keeping only those it matches; see the module docs for the expression syntax.
+pub trait FilterIterableOps {
+    type Item;
+    fn filter(&self, expr: &str) -> Result<Vec<Self::Item>, GraphError>;
+}
+
+impl<G: GraphViewOps> FilterIterableOps for Vertices<G> {
+    type Item = VertexView<G, G>;
+
+    fn filter(&self, expr: &str) -> Result<Vec<Self::Item>, GraphError> {
+        let ast = parse_filter(expr)?;
+        self.iter()
+            .filter_map(|v| {
+                use crate::prelude::VertexViewOps;
+                match ast.eval(&|name| v.properties().get(name)) {
+                    Ok(true) => Some(Ok(v)),
+                    Ok(false) => None,
+                    Err(e) => Some(Err(e)),
+                }
+            })
+            .collect()
+    }
+}
+
+impl<G: GraphViewOps> FilterIterableOps for Edges<G> {
+    type Item = EdgeView<G, G>;
+
+    fn filter(&self, expr: &str) -> Result<Vec<Self::Item>, GraphError> {
+        let ast = parse_filter(expr)?;
+        self.iter()
+            .filter_map(|e| {
+                use crate::prelude::EdgeViewOps;
+                match ast.eval(&|name| e.properties().get(name)) {
+                    Ok(true) => Some(Ok(e)),
+                    Ok(false) => None,
+                    Err(e) => Some(Err(e)),
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{parse_filter, FilterOps};
+    use crate::{
+        core::Prop,
+        db::{api::mutation::AdditionOps, graph::graph::Graph},
+        prelude::*,
+    };
+    use std::collections::HashMap;
+
+    fn resolver(props: HashMap<&str, Prop>) -> impl Fn(&str) -> Option<Prop> {
+        move |name| props.get(name).cloned()
+    }
+
+    #[test]
+    fn and_binds_tighter_than_or() {
+        // `a && b || c` must parse as `(a && b) || c`, so with a=true, b=false, c=true the
+        // overall result is true even though `a && b` alone is false.
+        let expr = parse_filter("a && b || c").unwrap();
+        let resolve = resolver(HashMap::from([
+            ("a", Prop::Bool(true)),
+            ("b", Prop::Bool(false)),
+            ("c", Prop::Bool(true)),
+        ]));
+        assert!(expr.eval(&resolve).unwrap());
+    }
+
+    #[test]
+    fn numeric_and_string_comparisons_combine_with_and() {
+        let expr = parse_filter("weight > 10 && layer == 'btc'").unwrap();
+        let resolve = resolver(HashMap::from([
+            ("weight", Prop::F64(15.0)),
+            ("layer", Prop::Str("btc".into())),
+        ]));
+        assert!(expr.eval(&resolve).unwrap());
+
+        let resolve_low_weight = resolver(HashMap::from([
+            ("weight", Prop::F64(5.0)),
+            ("layer", Prop::Str("btc".into())),
+        ]));
+        assert!(!expr.eval(&resolve_low_weight).unwrap());
+    }
+
+    #[test]
+    fn missing_property_makes_the_comparison_false_not_an_error() {
+        let expr = parse_filter("weight > 10").unwrap();
+        let resolve = resolver(HashMap::new());
+        assert_eq!(expr.eval(&resolve).unwrap(), false);
+    }
+
+    #[test]
+    fn parenthesized_or_overrides_default_precedence() {
+        // Without parens, `a && b || c` binds as `(a && b) || c`, which is true for a=false,
+        // b=false, c=true; the parens force the `||` to evaluate first, so `a && (b || c)` is false.
+        let expr = parse_filter("a && (b || c)").unwrap();
+        let resolve = resolver(HashMap::from([
+            ("a", Prop::Bool(false)),
+            ("b", Prop::Bool(false)),
+            ("c", Prop::Bool(true)),
+        ]));
+        assert!(!expr.eval(&resolve).unwrap());
+    }
+
+    #[test]
+    fn matches_filter_evaluates_against_a_real_vertex() {
+        let g = Graph::new();
+        g.add_vertex(0, "a", [("weight".to_string(), Prop::F64(15.0))])
+            .unwrap();
+        let v = g.vertex("a").unwrap();
+        assert!(v.matches_filter("weight > 10").unwrap());
+        assert!(!v.matches_filter("weight > 100").unwrap());
+    }
+
+    #[test]
+    fn filter_keeps_only_the_vertices_that_match() {
+        use super::FilterIterableOps;
+
+        let g = Graph::new();
+        g.add_vertex(0, "a", [("weight".to_string(), Prop::F64(15.0))])
+            .unwrap();
+        g.add_vertex(0, "b", [("weight".to_string(), Prop::F64(5.0))])
+            .unwrap();
+        let matched = g.vertices().filter("weight > 10").unwrap();
+        assert_eq!(
+            matched.into_iter().map(|v| v.name()).collect::<Vec<_>>(),
+            vec!["a"]
+        );
+    }
+
+    #[test]
+    fn filter_keeps_only_the_edges_that_match() {
+        use super::FilterIterableOps;
+
+        let g = Graph::new();
+        g.add_edge(0, "a", "b", [("weight".to_string(), Prop::F64(15.0))], None)
+            .unwrap();
+        g.add_edge(0, "b", "c", [("weight".to_string(), Prop::F64(5.0))], None)
+            .unwrap();
+        let matched = g.edges().filter("weight > 10").unwrap();
+        assert_eq!(matched.len(), 1);
+    }
+}
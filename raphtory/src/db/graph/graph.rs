@@ -17,7 +17,7 @@
 //!
 
 use crate::{
-    core::{entities::graph::tgraph::InnerTemporalGraph, utils::errors::GraphError},
+    core::{entities::graph::tgraph::InnerTemporalGraph, utils::errors::GraphError, Prop},
     db::api::{
         mutation::internal::{InheritAdditionOps, InheritPropertyAdditionOps},
         view::internal::{Base, DynamicGraph, InheritViewOps, IntoDynamic, MaterializedGraph},
@@ -134,6 +134,89 @@ impl Graph {
     pub fn as_arc(&self) -> Arc<InternalGraph> {
         self.0.clone()
     }
+
+    /// Load a graph from a 0/1 adjacency-matrix text format.
+    ///
+    /// Each line is split on whitespace into a row of 0/1 tokens; a `1` at row `i`, column `j`
+    /// adds the edge `(i, j)` at time `t`, using the row/column indices as vertex ids. Every
+    /// token must parse as `0` or `1`. This is a thin convenience wrapper around
+    /// [`parse_adjacency_matrix`](crate::graph_loader::source::adjacency_matrix_loader::parse_adjacency_matrix),
+    /// the parser also used by [`AdjacencyMatrixLoader`](crate::graph_loader::source::adjacency_matrix_loader::AdjacencyMatrixLoader);
+    /// reach for that loader instead if you're reading from a file path or need the weighted
+    /// (cell-as-time) variant.
+    ///
+    /// # Arguments
+    ///
+    /// * `reader` - Any reader over the matrix text.
+    /// * `t` - The timestamp applied to every edge created from the matrix.
+    pub fn load_from_adjacency_matrix<R: std::io::BufRead>(
+        reader: R,
+        t: i64,
+    ) -> Result<Self, GraphError> {
+        let graph = Self::new();
+        crate::graph_loader::source::adjacency_matrix_loader::parse_adjacency_matrix(
+            &graph, reader, false, t,
+        )?;
+        Ok(graph)
+    }
+
+    /// Load a graph from a text edge list, one edge per line: `src dst [time] [weight]`.
+    ///
+    /// `default_time` is used for any line that omits the optional time column. Malformed lines
+    /// return a [`GraphError`] annotated with the 1-based line number.
+    ///
+    /// Note this is a different column order from
+    /// [`edge_list_to_graph`](crate::graph_loader::source::text_loader::edge_list_to_graph)'s
+    /// `t src dst [weight] [layer]` format — the two are not interchangeable, so don't swap one
+    /// parser's input into the other.
+    pub fn load_from_edge_list<R: std::io::BufRead>(
+        reader: R,
+        default_time: i64,
+    ) -> Result<Self, GraphError> {
+        let graph = Self::new();
+        for (idx, line) in reader.lines().enumerate() {
+            let line_no = idx + 1;
+            let line = line.map_err(GraphError::from)?;
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            let tokens: Vec<&str> = line.split_whitespace().collect();
+            if tokens.len() < 2 {
+                return Err(GraphError::LoadError {
+                    line: line_no,
+                    message: "expected at least 'src dst'".to_string(),
+                });
+            }
+            let src: u64 = tokens[0].parse().map_err(|_| GraphError::LoadError {
+                line: line_no,
+                message: format!("invalid src id '{}'", tokens[0]),
+            })?;
+            let dst: u64 = tokens[1].parse().map_err(|_| GraphError::LoadError {
+                line: line_no,
+                message: format!("invalid dst id '{}'", tokens[1]),
+            })?;
+            let time = match tokens.get(2) {
+                Some(t) => t.parse().map_err(|_| GraphError::LoadError {
+                    line: line_no,
+                    message: format!("invalid time '{t}'"),
+                })?,
+                None => default_time,
+            };
+            let props: Vec<(String, Prop)> = match tokens.get(3) {
+                Some(w) => {
+                    let weight: f64 = w.parse().map_err(|_| GraphError::LoadError {
+                        line: line_no,
+                        message: format!("invalid weight '{w}'"),
+                    })?;
+                    vec![("weight".to_string(), Prop::F64(weight))]
+                }
+                None => vec![],
+            };
+            graph.add_edge(time, src, dst, props, None)?;
+        }
+        Ok(graph)
+    }
 }
 
 impl IntoDynamic for Graph {
@@ -1538,4 +1621,32 @@ mod db_tests {
             Intervals(intervals)
         }
     }
+
+    #[test]
+    fn load_from_edge_list_reads_src_dst_time_weight_columns_in_that_order() {
+        // column order is `src dst [time] [weight]`, NOT the `t src dst [weight] [layer]` order
+        // used by `graph_loader::source::text_loader::edge_list_to_graph` — pinning that here so
+        // a future refactor can't silently swap one parser's format into the other.
+        let text = "1 2 10 3.5\n1 3\n";
+        let g = Graph::load_from_edge_list(text.as_bytes(), 0).unwrap();
+
+        assert_eq!(g.num_vertices(), 3);
+        let e = g.edge(1, 2).unwrap();
+        assert_eq!(e.earliest_time(), Some(10));
+        assert_eq!(
+            e.properties().get("weight"),
+            Some(Prop::F64(3.5))
+        );
+
+        // The line with no time column falls back to `default_time`.
+        let e2 = g.edge(1, 3).unwrap();
+        assert_eq!(e2.earliest_time(), Some(0));
+    }
+
+    #[test]
+    fn load_from_edge_list_rejects_a_malformed_line_with_its_line_number() {
+        let text = "1 2\nnot_a_number 4\n";
+        let err = Graph::load_from_edge_list(text.as_bytes(), 0).unwrap_err();
+        assert!(matches!(err, GraphError::LoadError { line: 2, .. }));
+    }
 }
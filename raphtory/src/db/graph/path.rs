@@ -12,9 +12,278 @@ use crate::{
     },
     prelude::*,
 };
-use std::sync::Arc;
+use std::{
+    cmp::Ordering,
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    sync::Arc,
+};
 
 pub(crate) type Operation<'a> = Arc<dyn Fn(VID) -> BoxedLIter<'a, VID> + Send + Sync + 'a>;
+
+/// A min-heap entry for [`PathFromVertex::shortest_path`]/[`PathFromVertex::distances`]'s
+/// Dijkstra frontier: ordered by ascending `cost` (the `Ord` impl reverses the comparison so
+/// [`BinaryHeap`], a max-heap, pops the cheapest entry first). Decrease-key is handled by simply
+/// pushing a new, cheaper entry for a vertex instead of mutating the heap in place; a popped entry
+/// whose vertex is already settled is a stale duplicate and is skipped.
+struct MinScored(f64, VID);
+
+impl PartialEq for MinScored {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for MinScored {}
+
+impl PartialOrd for MinScored {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for MinScored {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.partial_cmp(&self.0).unwrap_or(Ordering::Equal)
+    }
+}
+
+/// The walk backing [`PathFromVertex::bfs`]/[`PathFromVertex::dfs`] and
+/// [`PathFromGraph::bfs`]/[`PathFromGraph::dfs`]: a classic visited-set-bounded graph walk (in the
+/// style of petgraph's `Bfs`/`Dfs` visitors), expanding one vertex per `next()` call via the
+/// existing one-hop `op` rather than the unguarded `flat_map` that backs `hop`, so cyclic graphs
+/// terminate and each reachable `VID` is yielded exactly once.
+struct BoundedWalk<'graph> {
+    op: Operation<'graph>,
+    frontier: VecDeque<(VID, usize)>,
+    visited: HashSet<VID>,
+    max_depth: usize,
+    breadth_first: bool,
+}
+
+impl<'graph> Iterator for BoundedWalk<'graph> {
+    type Item = VID;
+
+    fn next(&mut self) -> Option<VID> {
+        loop {
+            let (vertex, depth) = if self.breadth_first {
+                self.frontier.pop_front()?
+            } else {
+                self.frontier.pop_back()?
+            };
+            if !self.visited.insert(vertex) {
+                continue;
+            }
+            if depth < self.max_depth {
+                for neighbour in (self.op)(vertex) {
+                    if !self.visited.contains(&neighbour) {
+                        self.frontier.push_back((neighbour, depth + 1));
+                    }
+                }
+            }
+            return Some(vertex);
+        }
+    }
+}
+
+/// Seeds a [`BoundedWalk`] from `start`'s one-hop neighbours (depth 1) via `op`, walking out to
+/// `max_depth` in breadth-first or depth-first order.
+fn bounded_walk<'graph>(
+    op: Operation<'graph>,
+    start: VID,
+    max_depth: usize,
+    breadth_first: bool,
+) -> BoxedLIter<'graph, VID> {
+    let frontier: VecDeque<(VID, usize)> = op(start).map(|v| (v, 1)).collect();
+    Box::new(BoundedWalk {
+        op,
+        frontier,
+        visited: HashSet::new(),
+        max_depth,
+        breadth_first,
+    })
+}
+
+/// Builds a [`BoundedWalk`] seeded with every vertex in `roots` at depth 0, so each root is itself
+/// the first thing the walk yields, sharing one visited set across all of them — a vertex
+/// reachable from two different roots is still only produced once. This is the multi-root
+/// counterpart to the single-root [`bounded_walk`] above (used internally by
+/// [`PathFromVertex::bfs`]/[`PathFromGraph::bfs`] and their `dfs` counterparts, which only ever
+/// have one root); `PyVertex::bfs`/`dfs` and `PyPathFromVertex::bfs`/`dfs`
+/// (`python/graph/vertex.rs`) call this one directly instead of maintaining a second bounded-walk
+/// implementation of their own.
+pub(crate) fn bounded_walk_from_roots<'graph>(
+    op: Operation<'graph>,
+    roots: impl IntoIterator<Item = VID>,
+    max_depth: usize,
+    breadth_first: bool,
+) -> BoxedLIter<'graph, VID> {
+    let frontier: VecDeque<(VID, usize)> = roots.into_iter().map(|v| (v, 0)).collect();
+    Box::new(BoundedWalk {
+        op,
+        frontier,
+        visited: HashSet::new(),
+        max_depth,
+        breadth_first,
+    })
+}
+
+/// The walk backing [`PathFromVertex::id_ordered_ancestors`]/
+/// [`PathFromVertex::id_ordered_descendants`]: a lazy DAG-reachability walk ordered by a
+/// `BinaryHeap<(u64, VID)>` of candidate node keys (the vertex *id*, the only ordering key the
+/// generic one-hop `op` exposes — this is not a timestamp) plus a `HashSet<VID>` of already-seen
+/// vertices, so the heap's maximum is popped and yielded one vertex at a time without ever
+/// materializing the full reachable set. Each pop is the largest id among the vertices discovered
+/// so far; the yielded sequence as a whole is not sorted, since a vertex discovered later can have
+/// a larger id than one already yielded.
+///
+/// This is unrelated to [`TemporalHeapWalk`], which backs
+/// [`PathFromVertex::temporal_ancestors`]/[`PathFromVertex::temporal_descendants`] and walks
+/// `edge.history()` to track genuine arrival times — don't reach for this type when you actually
+/// need recency.
+struct HeapWalk<'graph, G> {
+    graph: G,
+    op: Operation<'graph>,
+    heap: BinaryHeap<(u64, VID)>,
+    seen: HashSet<VID>,
+}
+
+impl<'graph, G: GraphViewOps<'graph>> Iterator for HeapWalk<'graph, G> {
+    type Item = VID;
+
+    fn next(&mut self) -> Option<VID> {
+        loop {
+            let (_, vertex) = self.heap.pop()?;
+            if !self.seen.insert(vertex) {
+                continue;
+            }
+            for neighbour in (self.op)(vertex) {
+                if !self.seen.contains(&neighbour) {
+                    let key = VertexView::new_internal(self.graph.clone(), neighbour).id();
+                    self.heap.push((key, neighbour));
+                }
+            }
+            return Some(vertex);
+        }
+    }
+}
+
+/// The walk backing [`PathFromVertex::temporal_ancestors`]/[`PathFromVertex::temporal_descendants`]:
+/// a lazy, genuinely time-respecting reachability walk. Unlike [`HeapWalk`], the heap key here is
+/// each vertex's *arrival time* rather than its id: descendants relax forward through
+/// `edge.history()`, keeping the earliest time information could have reached a vertex (mirroring
+/// [`temporal_walk`](crate::python::graph::vertex) in `python/graph/vertex.rs`), and ancestors
+/// relax backward, keeping the latest time a vertex could have sent information onward. The
+/// forward walk keys the heap on the negated time, so descendants come off it in ascending
+/// arrival-time order (earliest first); ancestors come off in descending order (latest first).
+/// Either way a caller can stop iterating as soon as times pass a threshold.
+struct TemporalHeapWalk<'graph, G> {
+    graph: G,
+    heap: BinaryHeap<(i64, VID)>,
+    best: HashMap<VID, i64>,
+    seen: HashSet<VID>,
+    descendants: bool,
+    _marker: std::marker::PhantomData<&'graph ()>,
+}
+
+impl<'graph, G: GraphViewOps<'graph>> Iterator for TemporalHeapWalk<'graph, G> {
+    type Item = VID;
+
+    fn next(&mut self) -> Option<VID> {
+        loop {
+            let (key, vertex) = self.heap.pop()?;
+            let time = if self.descendants { -key } else { key };
+            if !self.seen.insert(vertex) {
+                continue;
+            }
+
+            let view = VertexView::new_internal(self.graph.clone(), vertex);
+            let edges: Vec<_> = if self.descendants {
+                view.out_edges().collect()
+            } else {
+                view.in_edges().collect()
+            };
+            for edge in edges {
+                let neighbour = if self.descendants {
+                    edge.dst().node
+                } else {
+                    edge.src().node
+                };
+                if self.seen.contains(&neighbour) {
+                    continue;
+                }
+                let candidate = if self.descendants {
+                    edge.history().into_iter().filter(|&te| te >= time).min()
+                } else {
+                    edge.history().into_iter().filter(|&te| te <= time).max()
+                };
+                let Some(te) = candidate else { continue };
+
+                let is_better = match self.best.get(&neighbour) {
+                    None => true,
+                    Some(&best_t) => {
+                        if self.descendants {
+                            te < best_t
+                        } else {
+                            te > best_t
+                        }
+                    }
+                };
+                if is_better {
+                    self.best.insert(neighbour, te);
+                    let key = if self.descendants { -te } else { te };
+                    self.heap.push((key, neighbour));
+                }
+            }
+            return Some(vertex);
+        }
+    }
+}
+
+/// Seeds a [`TemporalHeapWalk`] with `start`'s own arrival time (its earliest activity for a
+/// forward/descendants walk, or its latest for a backward/ancestors walk) so it is the first
+/// vertex yielded. Returns an empty walk if `start` has no recorded activity to seed from.
+fn temporal_heap_walk<'graph, G: GraphViewOps<'graph>>(
+    graph: G,
+    start: VID,
+    descendants: bool,
+) -> BoxedLIter<'graph, VID> {
+    let start_view = VertexView::new_internal(graph.clone(), start);
+    let t0 = if descendants {
+        start_view.earliest_time()
+    } else {
+        start_view.latest_time()
+    };
+    let Some(t0) = t0 else {
+        return Box::new(std::iter::empty());
+    };
+    let mut heap = BinaryHeap::new();
+    heap.push((if descendants { -t0 } else { t0 }, start));
+    Box::new(TemporalHeapWalk {
+        graph,
+        heap,
+        best: HashMap::new(),
+        seen: HashSet::new(),
+        descendants,
+        _marker: std::marker::PhantomData,
+    })
+}
+
+/// Seeds a [`HeapWalk`] with `start`'s own key so it is the first vertex yielded.
+fn heap_walk<'graph, G: GraphViewOps<'graph>>(
+    graph: G,
+    op: Operation<'graph>,
+    start: VID,
+) -> BoxedLIter<'graph, VID> {
+    let start_key = VertexView::new_internal(graph.clone(), start).id();
+    let mut heap = BinaryHeap::new();
+    heap.push((start_key, start));
+    Box::new(HeapWalk {
+        graph,
+        op,
+        heap,
+        seen: HashSet::new(),
+    })
+}
 #[derive(Clone)]
 pub struct PathFromGraph<'graph, G, GH> {
     pub(crate) graph: GH,
@@ -61,6 +330,26 @@ impl<'graph, G: GraphViewOps<'graph>, GH: GraphViewOps<'graph>> PathFromGraph<'g
         let op = self.op.clone();
         self.base_iter().map(move |vid| op(vid))
     }
+
+    /// Breadth-first walk out to `max_depth` from each vertex in the graph, visiting each
+    /// reachable vertex exactly once. See [`PathFromVertex::bfs`] for the visited-set guarantee
+    /// that makes this safe on cyclic graphs.
+    pub fn bfs(&self, max_depth: usize) -> PathFromGraph<'graph, G, G> {
+        let op = self.op.clone();
+        PathFromGraph::new(self.base_graph.clone(), move |start| {
+            bounded_walk(op.clone(), start, max_depth, true)
+        })
+    }
+
+    /// Depth-first walk out to `max_depth` from each vertex in the graph, visiting each reachable
+    /// vertex exactly once. See [`PathFromVertex::bfs`] for the visited-set guarantee that makes
+    /// this safe on cyclic graphs.
+    pub fn dfs(&self, max_depth: usize) -> PathFromGraph<'graph, G, G> {
+        let op = self.op.clone();
+        PathFromGraph::new(self.base_graph.clone(), move |start| {
+            bounded_walk(op.clone(), start, max_depth, false)
+        })
+    }
 }
 
 impl<'graph, G: GraphViewOps<'graph>, GH: GraphViewOps<'graph>> InternalLayerOps
@@ -235,6 +524,121 @@ impl<'graph, G: GraphViewOps<'graph>, GH: GraphViewOps<'graph>> PathFromVertex<'
         Box::new(iter)
     }
 
+    /// Breadth-first walk out to `max_depth`, visiting each reachable vertex exactly once.
+    /// Unlike [`hop`](BaseVertexViewOps::hop), which blindly `flat_map`s one layer of neighbours
+    /// onto the next, this is safe on cyclic graphs: a [`HashSet<VID>`] visited-set stops the walk
+    /// from looping forever or yielding the same vertex twice.
+    pub fn bfs(&self, max_depth: usize) -> PathFromVertex<'graph, G, G> {
+        let op = self.op.clone();
+        PathFromVertex::new(self.base_graph.clone(), self.vertex, move |start| {
+            bounded_walk(op.clone(), start, max_depth, true)
+        })
+    }
+
+    /// Depth-first walk out to `max_depth`, visiting each reachable vertex exactly once. See
+    /// [`bfs`](Self::bfs) for the visited-set guarantee that makes this safe on cyclic graphs.
+    pub fn dfs(&self, max_depth: usize) -> PathFromVertex<'graph, G, G> {
+        let op = self.op.clone();
+        PathFromVertex::new(self.base_graph.clone(), self.vertex, move |start| {
+            bounded_walk(op.clone(), start, max_depth, false)
+        })
+    }
+
+    /// Lazily enumerates the start vertex and then every vertex reachable through this path's
+    /// one-hop `op`, yielding each exactly once, ordered by *vertex id* — not a timestamp: each
+    /// step yields the largest id among the vertices discovered but not yet yielded, so the
+    /// sequence as a whole is not sorted. Call this on `.in_neighbours()` to walk predecessor
+    /// edges; see [`id_ordered_descendants`](Self::id_ordered_descendants) for the
+    /// successor-edge mirror.
+    ///
+    /// For a genuinely time-respecting ancestor walk, see [`temporal_ancestors`](Self::temporal_ancestors)
+    /// instead — this method does not look at edge history at all.
+    pub fn id_ordered_ancestors(&self) -> PathFromVertex<'graph, G, G> {
+        let op = self.op.clone();
+        let graph = self.base_graph.clone();
+        PathFromVertex::new(graph.clone(), self.vertex, move |start| {
+            heap_walk(graph.clone(), op.clone(), start)
+        })
+    }
+
+    /// Lazily enumerates the start vertex and then every vertex reachable through this path's
+    /// one-hop `op`, yielding each exactly once in the same id-driven, not globally sorted,
+    /// order as [`id_ordered_ancestors`](Self::id_ordered_ancestors). Call this on
+    /// `.out_neighbours()` to walk successor edges; see
+    /// [`id_ordered_ancestors`](Self::id_ordered_ancestors) for the predecessor-edge mirror.
+    ///
+    /// For a genuinely time-respecting descendant walk, see
+    /// [`temporal_descendants`](Self::temporal_descendants) instead — this method does not look
+    /// at edge history at all.
+    pub fn id_ordered_descendants(&self) -> PathFromVertex<'graph, G, G> {
+        let op = self.op.clone();
+        let graph = self.base_graph.clone();
+        PathFromVertex::new(graph.clone(), self.vertex, move |start| {
+            heap_walk(graph.clone(), op.clone(), start)
+        })
+    }
+
+    /// Lazily walks predecessor edges backward from this path's vertex, yielding each reachable
+    /// vertex exactly once in descending order of *arrival time* — the latest time it could have
+    /// sent information on to `self.vertex` without crossing its own later activity. See
+    /// [`temporal_descendants`](Self::temporal_descendants) for the successor-edge mirror.
+    /// Because the heap order means arrival times only ever decrease, callers can stop early once
+    /// times drop below a threshold.
+    pub fn temporal_ancestors(&self) -> PathFromVertex<'graph, G, G> {
+        let base = self.base_graph.clone();
+        PathFromVertex::new(base.clone(), self.vertex, move |start| {
+            temporal_heap_walk(base.clone(), start, false)
+        })
+    }
+
+    /// Lazily walks successor edges forward from this path's vertex, yielding each reachable
+    /// vertex exactly once in ascending order of *arrival time* — the earliest time information
+    /// starting at `self.vertex` could have reached it. Because arrival times only ever increase,
+    /// callers can stop early once times rise past a threshold. See
+    /// [`temporal_ancestors`](Self::temporal_ancestors) for the predecessor-edge mirror.
+    pub fn temporal_descendants(&self) -> PathFromVertex<'graph, G, G> {
+        let base = self.base_graph.clone();
+        PathFromVertex::new(base.clone(), self.vertex, move |start| {
+            temporal_heap_walk(base.clone(), start, true)
+        })
+    }
+
+    /// Runs Dijkstra's algorithm from this path's vertex over `weight_fn`, a closure mapping an
+    /// out-edge to its non-negative cost, and returns every reachable vertex's distance.
+    pub fn distances<F: Fn(&EdgeView<G, GH>) -> f64>(&self, weight_fn: F) -> HashMap<VID, f64> {
+        self.dijkstra(weight_fn).0
+    }
+
+    /// As [`distances`](Self::distances), but only reconstructs and returns the cheapest route to
+    /// `target`: its vertices (starting at this path's vertex) and its total cost, or `None` if
+    /// `target` is unreachable.
+    pub fn shortest_path<F: Fn(&EdgeView<G, GH>) -> f64>(
+        &self,
+        target: VID,
+        weight_fn: F,
+    ) -> Option<(Vec<VID>, f64)> {
+        let (dist, prev) = self.dijkstra(weight_fn);
+        reconstruct_dijkstra_path(&dist, &prev, &HashSet::from([self.vertex]), target)
+    }
+
+    /// Dijkstra's algorithm with a [`MinScored`] binary-heap frontier: `dist` holds the best-known
+    /// distance to each settled (or frontier) vertex, and `prev` is the predecessor map used to
+    /// reconstruct a route in [`shortest_path`](Self::shortest_path). A thin, single-root wrapper
+    /// over [`dijkstra_from_roots`], the multi-root version `PyVertex::shortest_path` and
+    /// `PyPathFromVertex::shortest_path` (`python/graph/vertex.rs`) call directly for the "out"
+    /// direction.
+    fn dijkstra<F: Fn(&EdgeView<G, GH>) -> f64>(
+        &self,
+        weight_fn: F,
+    ) -> (HashMap<VID, f64>, HashMap<VID, VID>) {
+        dijkstra_from_roots(
+            &self.base_graph,
+            &self.graph,
+            std::iter::once(self.vertex),
+            weight_fn,
+        )
+    }
+
     pub(crate) fn new_one_hop_filtered(
         base_graph: G,
         graph: GH,
@@ -250,6 +654,73 @@ impl<'graph, G: GraphViewOps<'graph>, GH: GraphViewOps<'graph>> PathFromVertex<'
     }
 }
 
+/// Multi-source counterpart to [`PathFromVertex::dijkstra`]: runs Dijkstra from every vertex in
+/// `roots` at once (each starting at cost `0.0`), walking each settled vertex's out-edges via
+/// `weight_fn` for cost. `dist` holds the best-known distance to each settled (or frontier) vertex
+/// and `prev` the predecessor used to reconstruct a route back to *whichever* root reached it
+/// cheapest. `PyVertex::shortest_path`/`PyPathFromVertex::shortest_path`
+/// (`python/graph/vertex.rs`) call this directly for the "out" direction instead of maintaining a
+/// second Dijkstra frontier of their own.
+pub(crate) fn dijkstra_from_roots<'graph, G, GH, F>(
+    base_graph: &G,
+    graph: &GH,
+    roots: impl IntoIterator<Item = VID>,
+    weight_fn: F,
+) -> (HashMap<VID, f64>, HashMap<VID, VID>)
+where
+    G: GraphViewOps<'graph>,
+    GH: GraphViewOps<'graph>,
+    F: Fn(&EdgeView<G, GH>) -> f64,
+{
+    let mut dist: HashMap<VID, f64> = HashMap::new();
+    let mut prev: HashMap<VID, VID> = HashMap::new();
+    let mut settled: HashSet<VID> = HashSet::new();
+    let mut heap = BinaryHeap::new();
+    for root in roots {
+        dist.insert(root, 0.0);
+        heap.push(MinScored(0.0, root));
+    }
+
+    while let Some(MinScored(cost, u)) = heap.pop() {
+        if !settled.insert(u) {
+            continue;
+        }
+        let u_view = VertexView::new_one_hop_filtered(base_graph.clone(), graph.clone(), u);
+        for edge in u_view.out_edges() {
+            let v = edge.dst().vertex;
+            let candidate = cost + weight_fn(&edge);
+            if candidate < *dist.get(&v).unwrap_or(&f64::INFINITY) {
+                dist.insert(v, candidate);
+                prev.insert(v, u);
+                heap.push(MinScored(candidate, v));
+            }
+        }
+    }
+
+    (dist, prev)
+}
+
+/// Reconstructs the cheapest route to `target` from `dist`/`prev` (as produced by
+/// [`dijkstra_from_roots`]), stopping as soon as the walk back from `target` reaches any vertex in
+/// `roots` rather than insisting on a single fixed start. Returns `None` if `target` is
+/// unreachable.
+pub(crate) fn reconstruct_dijkstra_path(
+    dist: &HashMap<VID, f64>,
+    prev: &HashMap<VID, VID>,
+    roots: &HashSet<VID>,
+    target: VID,
+) -> Option<(Vec<VID>, f64)> {
+    let cost = *dist.get(&target)?;
+    let mut route = vec![target];
+    let mut cur = target;
+    while !roots.contains(&cur) {
+        cur = *prev.get(&cur)?;
+        route.push(cur);
+    }
+    route.reverse();
+    Some((route, cost))
+}
+
 impl<'graph, G: GraphViewOps<'graph>, GH: GraphViewOps<'graph>> InternalLayerOps
     for PathFromVertex<'graph, G, GH>
 {
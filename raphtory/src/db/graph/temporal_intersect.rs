@@ -0,0 +1,113 @@
+//! # Temporal Intersection Between Two Graph Views
+//!
+//! Generalizes "when were both these two edges active" ([`TimeIntervalSet::intersection`]) from
+//! a single edge pair to whole subgraphs: `view_a.temporal_intersect(view_b)` walks every edge
+//! present in `view_a`, and for each one also present in `view_b`, intersects their coalesced
+//! activity intervals. Edges present in only one view, or whose activity never overlaps, are
+//! left out of the result entirely rather than being reported with an empty interval set.
+use crate::{
+    core::utils::time_interval_set::TimeIntervalSet,
+    db::api::view::StaticGraphViewOps,
+    prelude::{EdgeViewOps, GraphViewOps},
+};
+
+/// For each edge `(src, dst)` present in both `a` and `b`, the set of time intervals during
+/// which it is active in both, keyed by `(src, dst)`.
+pub fn temporal_intersect<G1, G2>(a: &G1, b: &G2) -> Vec<(u64, u64, TimeIntervalSet)>
+where
+    G1: StaticGraphViewOps,
+    G2: StaticGraphViewOps,
+{
+    let mut result = Vec::new();
+    for edge in a.edges() {
+        let src = edge.src().id();
+        let dst = edge.dst().id();
+        let Some(other_edge) = b.edge(src, dst) else {
+            continue;
+        };
+
+        let a_intervals = TimeIntervalSet::from_timestamps(edge.history());
+        let b_intervals = TimeIntervalSet::from_timestamps(other_edge.history());
+        let overlap = a_intervals.intersection(&b_intervals);
+        if !overlap.is_empty() {
+            result.push((src, dst, overlap));
+        }
+    }
+    result
+}
+
+/// Fluent access to [`temporal_intersect`] directly on a graph view.
+pub trait TemporalIntersectOps<G: StaticGraphViewOps> {
+    /// For each edge present in both `self` and `other`, the set of time intervals during which
+    /// it is active in both.
+    fn temporal_intersect<G2: StaticGraphViewOps>(
+        &self,
+        other: &G2,
+    ) -> Vec<(u64, u64, TimeIntervalSet)>;
+}
+
+impl<G: StaticGraphViewOps> TemporalIntersectOps<G> for G {
+    fn temporal_intersect<G2: StaticGraphViewOps>(
+        &self,
+        other: &G2,
+    ) -> Vec<(u64, u64, TimeIntervalSet)> {
+        temporal_intersect(self, other)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::temporal_intersect;
+    use crate::{
+        db::{api::mutation::AdditionOps, graph::graph::Graph},
+        prelude::*,
+    };
+
+    #[test]
+    fn only_the_overlapping_slice_of_a_shared_edges_activity_is_reported() {
+        let a = Graph::new();
+        a.add_edge(1, "a", "b", NO_PROPS, None).unwrap();
+        a.add_edge(5, "a", "b", NO_PROPS, None).unwrap();
+
+        let b = Graph::new();
+        b.add_edge(5, "a", "b", NO_PROPS, None).unwrap();
+        b.add_edge(8, "a", "b", NO_PROPS, None).unwrap();
+
+        let src = a.node("a").unwrap().id();
+        let dst = a.node("b").unwrap().id();
+
+        let result = temporal_intersect(&a, &b);
+        assert_eq!(result.len(), 1);
+        let (r_src, r_dst, overlap) = &result[0];
+        assert_eq!((*r_src, *r_dst), (src, dst));
+        assert_eq!(overlap.intervals(), &[(5, 6)]);
+    }
+
+    #[test]
+    fn an_edge_present_on_only_one_side_is_left_out_entirely() {
+        let a = Graph::new();
+        a.add_edge(1, "a", "b", NO_PROPS, None).unwrap();
+        a.add_edge(1, "a", "c", NO_PROPS, None).unwrap(); // only in `a`
+
+        let b = Graph::new();
+        b.add_edge(1, "a", "b", NO_PROPS, None).unwrap();
+        b.add_edge(1, "a", "d", NO_PROPS, None).unwrap(); // only in `b`
+
+        let result = temporal_intersect(&a, &b);
+        let src = a.node("a").unwrap().id();
+        let dst = a.node("b").unwrap().id();
+        assert_eq!(result.len(), 1);
+        assert_eq!((result[0].0, result[0].1), (src, dst));
+    }
+
+    #[test]
+    fn a_shared_edge_whose_activity_never_overlaps_is_left_out() {
+        let a = Graph::new();
+        a.add_edge(1, "a", "b", NO_PROPS, None).unwrap();
+
+        let b = Graph::new();
+        b.add_edge(100, "a", "b", NO_PROPS, None).unwrap();
+
+        assert!(temporal_intersect(&a, &b).is_empty());
+    }
+}
@@ -0,0 +1,338 @@
+//! # Gremlin-Style Graph Traversal
+//!
+//! A composable, introspectable alternative to hand-chaining iterators
+//! (`g.vertices().id()`, `one.neighbours().iter()`, ...) for building multi-step queries.
+//! A [`Traversal`] records each step (`.v()`, `.has(..)`, `.out(..)`, ...) instead of running it
+//! immediately, so the composed plan can be inspected with [`Traversal::explain`] or executed
+//! with instrumentation via [`Traversal::profile`], in addition to the usual
+//! [`Traversal::collect`]/[`Traversal::count`] terminals. Every step respects the window/layer
+//! selection of the graph view the traversal was built from.
+use crate::{
+    core::Prop,
+    db::graph::{edge::EdgeView, vertex::VertexView},
+    prelude::{EdgeViewOps, GraphViewOps, PropUnwrap, VertexViewOps},
+};
+use std::{
+    collections::HashSet,
+    time::{Duration, Instant},
+};
+
+/// A predicate used by [`Traversal::has`] to filter traversers by a property value. Build one
+/// with [`gt`], [`ge`], [`lt`], [`le`], or [`eq`].
+#[derive(Debug, Clone)]
+pub enum PropPredicate {
+    Gt(f64),
+    Ge(f64),
+    Lt(f64),
+    Le(f64),
+    Eq(Prop),
+}
+
+impl PropPredicate {
+    fn matches(&self, value: &Prop) -> bool {
+        match self {
+            PropPredicate::Gt(v) => value.clone().into_f64().is_some_and(|x| x > *v),
+            PropPredicate::Ge(v) => value.clone().into_f64().is_some_and(|x| x >= *v),
+            PropPredicate::Lt(v) => value.clone().into_f64().is_some_and(|x| x < *v),
+            PropPredicate::Le(v) => value.clone().into_f64().is_some_and(|x| x <= *v),
+            PropPredicate::Eq(v) => value == v,
+        }
+    }
+}
+
+pub fn gt(v: f64) -> PropPredicate {
+    PropPredicate::Gt(v)
+}
+pub fn ge(v: f64) -> PropPredicate {
+    PropPredicate::Ge(v)
+}
+pub fn lt(v: f64) -> PropPredicate {
+    PropPredicate::Lt(v)
+}
+pub fn le(v: f64) -> PropPredicate {
+    PropPredicate::Le(v)
+}
+pub fn eq(v: impl Into<Prop>) -> PropPredicate {
+    PropPredicate::Eq(v.into())
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Direction {
+    Out,
+    In,
+    Both,
+}
+
+#[derive(Debug, Clone)]
+enum Step {
+    V,
+    Has(String, PropPredicate),
+    Walk(Direction, Option<String>),
+    Dedup,
+    Limit(usize),
+}
+
+impl Step {
+    fn label(&self) -> String {
+        match self {
+            Step::V => "v()".to_string(),
+            Step::Has(name, predicate) => format!("has({name:?}, {predicate:?})"),
+            Step::Walk(Direction::Out, layer) => step_label("out", layer),
+            Step::Walk(Direction::In, layer) => step_label("in_", layer),
+            Step::Walk(Direction::Both, layer) => step_label("both", layer),
+            Step::Dedup => "dedup()".to_string(),
+            Step::Limit(n) => format!("limit({n})"),
+        }
+    }
+}
+
+fn step_label(name: &str, layer: &Option<String>) -> String {
+    match layer {
+        Some(l) => format!("{name}({l:?})"),
+        None => format!("{name}()"),
+    }
+}
+
+/// Per-step traverser counts and elapsed time, as returned by [`Traversal::profile`], mirroring
+/// the `TraversalMetrics` a Gremlin client receives.
+#[derive(Debug, Clone, Default)]
+pub struct TraversalMetrics {
+    pub steps: Vec<StepMetric>,
+}
+
+#[derive(Debug, Clone)]
+pub struct StepMetric {
+    pub label: String,
+    pub traversers_in: usize,
+    pub traversers_out: usize,
+    pub elapsed: Duration,
+}
+
+/// A Gremlin-style fluent query builder over a graph view. Steps are recorded, not executed,
+/// until a terminal operation (`collect`, `count`, `profile`) runs them.
+#[derive(Clone)]
+pub struct Traversal<G: GraphViewOps> {
+    graph: G,
+    steps: Vec<Step>,
+}
+
+impl<G: GraphViewOps> Traversal<G> {
+    pub fn new(graph: G) -> Self {
+        Self {
+            graph,
+            steps: Vec::new(),
+        }
+    }
+
+    /// Starts (or restarts) the traversal at every vertex in the graph view.
+    pub fn v(mut self) -> Self {
+        self.steps.push(Step::V);
+        self
+    }
+
+    /// Keeps only traversers whose `name` property satisfies `predicate`.
+    pub fn has(mut self, name: impl Into<String>, predicate: PropPredicate) -> Self {
+        self.steps.push(Step::Has(name.into(), predicate));
+        self
+    }
+
+    /// Moves to out-neighbours, optionally restricted to a single layer.
+    pub fn out(mut self, layer: impl Into<Option<String>>) -> Self {
+        self.steps.push(Step::Walk(Direction::Out, layer.into()));
+        self
+    }
+
+    /// Moves to in-neighbours, optionally restricted to a single layer.
+    pub fn in_(mut self, layer: impl Into<Option<String>>) -> Self {
+        self.steps.push(Step::Walk(Direction::In, layer.into()));
+        self
+    }
+
+    /// Moves to neighbours in either direction, optionally restricted to a single layer.
+    pub fn both(mut self, layer: impl Into<Option<String>>) -> Self {
+        self.steps.push(Step::Walk(Direction::Both, layer.into()));
+        self
+    }
+
+    /// Removes duplicate traversers, keeping the first occurrence of each vertex.
+    pub fn dedup(mut self) -> Self {
+        self.steps.push(Step::Dedup);
+        self
+    }
+
+    /// Keeps only the first `n` traversers.
+    pub fn limit(mut self, n: usize) -> Self {
+        self.steps.push(Step::Limit(n));
+        self
+    }
+
+    /// Executes the composed steps and returns the surviving vertices.
+    pub fn collect(&self) -> Vec<VertexView<G, G>> {
+        self.run(None).0
+    }
+
+    /// Executes the composed steps and returns how many vertices survive.
+    pub fn count(&self) -> usize {
+        self.collect().len()
+    }
+
+    /// Returns a human-readable rendering of the composed plan, without executing it.
+    pub fn explain(&self) -> String {
+        self.steps
+            .iter()
+            .map(Step::label)
+            .collect::<Vec<_>>()
+            .join(" -> ")
+    }
+
+    /// Executes the composed steps, recording per-step traverser counts and elapsed time.
+    pub fn profile(&self) -> TraversalMetrics {
+        self.run(Some(TraversalMetrics::default()))
+            .1
+            .expect("profile always requests metrics")
+    }
+
+    fn run(
+        &self,
+        mut metrics: Option<TraversalMetrics>,
+    ) -> (Vec<VertexView<G, G>>, Option<TraversalMetrics>) {
+        let mut current: Vec<VertexView<G, G>> = Vec::new();
+        for step in &self.steps {
+            let start = Instant::now();
+            let traversers_in = current.len();
+            current = match step {
+                Step::V => self.graph.vertices().iter().collect(),
+                Step::Has(name, predicate) => current
+                    .into_iter()
+                    .filter(|v| {
+                        v.properties()
+                            .temporal()
+                            .get(name)
+                            .and_then(|p| p.latest())
+                            .is_some_and(|value| predicate.matches(&value))
+                    })
+                    .collect(),
+                Step::Walk(direction, layer) => current
+                    .iter()
+                    .flat_map(|v| walk(v, *direction, layer.as_deref()))
+                    .collect(),
+                Step::Dedup => dedup_by_id(current),
+                Step::Limit(n) => current.into_iter().take(*n).collect(),
+            };
+            if let Some(m) = metrics.as_mut() {
+                m.steps.push(StepMetric {
+                    label: step.label(),
+                    traversers_in,
+                    traversers_out: current.len(),
+                    elapsed: start.elapsed(),
+                });
+            }
+        }
+        (current, metrics)
+    }
+}
+
+fn walk<G: GraphViewOps>(
+    v: &VertexView<G, G>,
+    direction: Direction,
+    layer: Option<&str>,
+) -> Vec<VertexView<G, G>> {
+    let edges: Vec<EdgeView<G, G>> = match direction {
+        Direction::Out => v.out_edges().into_iter().collect(),
+        Direction::In => v.in_edges().into_iter().collect(),
+        Direction::Both => v.out_edges().into_iter().chain(v.in_edges()).collect(),
+    };
+    edges
+        .into_iter()
+        .filter(|e| layer.map_or(true, |l| e.layer_name().as_deref() == Some(l)))
+        .map(|e| if e.src().id() == v.id() { e.dst() } else { e.src() })
+        .collect()
+}
+
+fn dedup_by_id<G: GraphViewOps>(vertices: Vec<VertexView<G, G>>) -> Vec<VertexView<G, G>> {
+    let mut seen = HashSet::new();
+    vertices.into_iter().filter(|v| seen.insert(v.id())).collect()
+}
+
+/// Fluent entry point: `g.traversal().v().out(None).collect()`.
+pub trait GraphTraversalOps<G: GraphViewOps> {
+    fn traversal(&self) -> Traversal<G>;
+}
+
+impl<G: GraphViewOps> GraphTraversalOps<G> for G {
+    fn traversal(&self) -> Traversal<G> {
+        Traversal::new(self.clone())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{ge, GraphTraversalOps};
+    use crate::{
+        core::Prop,
+        db::{api::mutation::AdditionOps, graph::graph::Graph},
+        prelude::*,
+    };
+    use std::collections::HashSet;
+
+    // a -> b, a -> c, b -> c, with vertex ages 30/10/40. Filtering to age >= 20 keeps a and c;
+    // walking out from those keeps only vertices actually reachable via an outgoing edge (b and
+    // c from a; nothing from c, which is a sink).
+    fn ages_graph() -> Graph {
+        let g = Graph::new();
+        g.add_vertex(0, "a", [("age".to_string(), Prop::F64(30.0))])
+            .unwrap();
+        g.add_vertex(0, "b", [("age".to_string(), Prop::F64(10.0))])
+            .unwrap();
+        g.add_vertex(0, "c", [("age".to_string(), Prop::F64(40.0))])
+            .unwrap();
+        g.add_edge(0, "a", "b", NO_PROPS, None).unwrap();
+        g.add_edge(0, "a", "c", NO_PROPS, None).unwrap();
+        g.add_edge(0, "b", "c", NO_PROPS, None).unwrap();
+        g
+    }
+
+    #[test]
+    fn has_filters_by_property_then_out_walks_to_neighbours() {
+        let g = ages_graph();
+        let result: HashSet<u64> = g
+            .traversal()
+            .v()
+            .has("age", ge(20.0))
+            .out(None)
+            .collect()
+            .into_iter()
+            .map(|v| v.id())
+            .collect();
+
+        let b = g.node("b").unwrap().id();
+        let c = g.node("c").unwrap().id();
+        assert_eq!(result, HashSet::from([b, c]));
+    }
+
+    #[test]
+    fn dedup_collapses_vertices_reached_by_multiple_paths() {
+        let g = ages_graph();
+        // Both a and b point at c, so walking out from {a, b} without dedup would yield c twice.
+        let count = g.traversal().v().out(None).dedup().count();
+        assert_eq!(count, 2); // b and c, each once
+    }
+
+    #[test]
+    fn explain_renders_the_composed_steps_in_order() {
+        let g = ages_graph();
+        let plan = g.traversal().v().has("age", ge(20.0)).out(None).explain();
+        assert_eq!(plan, "v() -> has(\"age\", Ge(20.0)) -> out()");
+    }
+
+    #[test]
+    fn profile_records_traverser_counts_per_step() {
+        let g = ages_graph();
+        let metrics = g.traversal().v().limit(2).profile();
+        assert_eq!(metrics.steps.len(), 2);
+        assert_eq!(metrics.steps[0].traversers_in, 0);
+        assert_eq!(metrics.steps[0].traversers_out, 3);
+        assert_eq!(metrics.steps[1].traversers_in, 3);
+        assert_eq!(metrics.steps[1].traversers_out, 2);
+    }
+}
@@ -0,0 +1,73 @@
+//! # Text Ingestion
+//!
+//! Builds a [`Graph`] from the compact text formats graph libraries commonly exchange, as an
+//! alternative to repeated `add_edge`/`add_vertex` calls: a 0/1 adjacency matrix, and a
+//! timestamped edge list. Both route through the existing `add_edge` path (and its layer
+//! machinery), so the resulting graph windows and layers exactly like one built by hand.
+use crate::{
+    core::{utils::errors::GraphError, Prop},
+    db::graph::graph::Graph,
+    graph_loader::source::adjacency_matrix_loader::parse_adjacency_matrix,
+    prelude::AdditionOps,
+};
+use std::io::BufRead;
+
+/// Parses a 0/1 adjacency matrix, one row per line, whitespace-separated columns. A `1` at row
+/// `r`, column `c` creates the edge `(r, c)` at `t`; every token must be `0` or `1`. A thin
+/// wrapper around [`parse_adjacency_matrix`] (the same parser [`AdjacencyMatrixLoader`] uses) —
+/// reach for that loader instead if you're reading from a file path or need the weighted
+/// (cell-as-time) variant.
+///
+/// [`AdjacencyMatrixLoader`]: crate::graph_loader::source::adjacency_matrix_loader::AdjacencyMatrixLoader
+pub fn adjacency_matrix_to_graph<R: BufRead>(reader: R, t: i64) -> Result<Graph, GraphError> {
+    let graph = Graph::new();
+    parse_adjacency_matrix(&graph, reader, false, t)?;
+    Ok(graph)
+}
+
+/// Parses a timestamped edge list, one edge per line: `t src dst [weight] [layer]`. Blank lines
+/// are skipped; any other malformed line returns a [`GraphError`] annotated with its 1-based
+/// line number.
+pub fn edge_list_to_graph<R: BufRead>(reader: R) -> Result<Graph, GraphError> {
+    let graph = Graph::new();
+    for (idx, line) in reader.lines().enumerate() {
+        let line_no = idx + 1;
+        let line = line.map_err(GraphError::from)?;
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        if tokens.len() < 3 {
+            return Err(GraphError::LoadError {
+                line: line_no,
+                message: "expected at least 't src dst'".to_string(),
+            });
+        }
+        let t: i64 = tokens[0].parse().map_err(|_| GraphError::LoadError {
+            line: line_no,
+            message: format!("invalid time '{}'", tokens[0]),
+        })?;
+        let src: u64 = tokens[1].parse().map_err(|_| GraphError::LoadError {
+            line: line_no,
+            message: format!("invalid src id '{}'", tokens[1]),
+        })?;
+        let dst: u64 = tokens[2].parse().map_err(|_| GraphError::LoadError {
+            line: line_no,
+            message: format!("invalid dst id '{}'", tokens[2]),
+        })?;
+        let props: Vec<(String, Prop)> = match tokens.get(3) {
+            Some(w) => {
+                let weight: f64 = w.parse().map_err(|_| GraphError::LoadError {
+                    line: line_no,
+                    message: format!("invalid weight '{w}'"),
+                })?;
+                vec![("weight".to_string(), Prop::F64(weight))]
+            }
+            None => vec![],
+        };
+        let layer = tokens.get(4).map(|l| l.to_string());
+        graph.add_edge(t, src, dst, props, layer.as_deref())?;
+    }
+    Ok(graph)
+}
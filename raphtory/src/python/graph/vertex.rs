@@ -16,7 +16,7 @@ use crate::{
             },
         },
         graph::{
-            path::{PathFromGraph, PathFromVertex},
+            path::{bounded_walk_from_roots, Operation, PathFromGraph, PathFromVertex},
             vertex::VertexView,
             vertices::Vertices,
             views::{
@@ -28,7 +28,7 @@ use crate::{
     prelude::Graph,
     python::{
         graph::{
-            edge::{PyEdges, PyNestedEdges},
+            edge::{PyEdge, PyEdges, PyNestedEdges},
             properties::{PyNestedPropsIterable, PyPropsList},
         },
         types::wrappers::iterators::*,
@@ -39,14 +39,17 @@ use crate::{
 use chrono::NaiveDateTime;
 use itertools::Itertools;
 use pyo3::{
-    exceptions::{PyIndexError, PyKeyError},
+    exceptions::{PyIndexError, PyKeyError, PyValueError},
     prelude::*,
     pyclass,
     pyclass::CompareOp,
     pymethods, PyAny, PyObject, PyRef, PyRefMut, PyResult, Python,
 };
 use python::types::repr::{iterator_repr, Repr};
-use std::{collections::HashMap, ops::Deref};
+use std::{
+    collections::{BinaryHeap, HashMap, HashSet, VecDeque},
+    ops::Deref,
+};
 
 /// A vertex (or node) in the graph.
 #[pyclass(name = "Vertex", subclass)]
@@ -287,6 +290,213 @@ impl PyVertex {
         self.vertex.history()
     }
 
+    /// Vertices reachable from this one via a time-respecting path, i.e. a path whose edge
+    /// timestamps are non-decreasing from hop to hop.
+    ///
+    /// Arguments:
+    ///     t0 (Optional[int]): The time to start the walk from. Defaults to this vertex's
+    ///         `earliest_time`.
+    ///     max_hops (Optional[int]): Maximum number of hops to take.
+    ///
+    /// Returns:
+    ///     TemporalPath: the reached vertices, each annotated with its earliest arrival time.
+    #[pyo3(signature = (t0=None, max_hops=None))]
+    pub fn temporal_descendants(
+        &self,
+        t0: Option<i64>,
+        max_hops: Option<usize>,
+    ) -> PyResult<PyTemporalPath> {
+        let start = t0
+            .or_else(|| self.vertex.earliest_time())
+            .ok_or_else(|| PyValueError::new_err("vertex has no events and no t0 was given"))?;
+        Ok(temporal_walk(&self.vertex, start, max_hops, true).into())
+    }
+
+    /// Vertices that can reach this one via a time-respecting path, i.e. a path whose edge
+    /// timestamps are non-increasing from hop to hop. The mirror image of
+    /// [`temporal_descendants`](Self::temporal_descendants).
+    ///
+    /// Arguments:
+    ///     t0 (Optional[int]): The time to start the walk from. Defaults to this vertex's
+    ///         `latest_time`.
+    ///     max_hops (Optional[int]): Maximum number of hops to take.
+    ///
+    /// Returns:
+    ///     TemporalPath: the reached vertices, each annotated with its latest arrival time.
+    #[pyo3(signature = (t0=None, max_hops=None))]
+    pub fn temporal_ancestors(
+        &self,
+        t0: Option<i64>,
+        max_hops: Option<usize>,
+    ) -> PyResult<PyTemporalPath> {
+        let start = t0
+            .or_else(|| self.vertex.latest_time())
+            .ok_or_else(|| PyValueError::new_err("vertex has no events and no t0 was given"))?;
+        Ok(temporal_walk(&self.vertex, start, max_hops, false).into())
+    }
+
+    /// Vertices reachable from this one, lazily discovered in breadth-first order. Nothing is
+    /// walked until the returned iterator is consumed, and each step only expands one node, so it
+    /// composes with the temporal views threaded through `impl_timeops!` instead of materialising
+    /// the whole traversal up front.
+    ///
+    /// Arguments:
+    ///     max_depth (Optional[int]): Maximum number of hops to explore.
+    ///     direction (str): One of `"out"`, `"in"` or `"both"`. Defaults to `"out"`.
+    ///
+    /// Returns:
+    ///     VertexIterator: the reachable vertices in discovery order.
+    #[pyo3(signature = (max_depth=None, direction="out"))]
+    pub fn bfs(&self, max_depth: Option<usize>, direction: &str) -> PyResult<PyVertexIterator> {
+        traversal_iterator(vec![self.vertex.clone()], max_depth, direction, true)
+    }
+
+    /// Vertices reachable from this one, lazily discovered in depth-first order. See
+    /// [`bfs`](Self::bfs) for the laziness guarantee; only the traversal order differs.
+    ///
+    /// Arguments:
+    ///     max_depth (Optional[int]): Maximum number of hops to explore.
+    ///     direction (str): One of `"out"`, `"in"` or `"both"`. Defaults to `"out"`.
+    ///
+    /// Returns:
+    ///     VertexIterator: the reachable vertices in discovery order.
+    #[pyo3(signature = (max_depth=None, direction="out"))]
+    pub fn dfs(&self, max_depth: Option<usize>, direction: &str) -> PyResult<PyVertexIterator> {
+        traversal_iterator(vec![self.vertex.clone()], max_depth, direction, false)
+    }
+
+    /// This vertex, then every vertex that can reach it by at least one predecessor edge,
+    /// enumerated lazily by vertex *id* — not a timestamp: each step yields the largest-id vertex
+    /// among those discovered but not yet yielded. The sequence as a whole is not sorted, since a
+    /// vertex discovered later can have a larger id than one already yielded. Backed directly by
+    /// [`PathFromVertex::id_ordered_ancestors`].
+    ///
+    /// This is unrelated to [`temporal_ancestors`](Self::temporal_ancestors)/
+    /// [`ancestors`](Self::ancestors), which walk edge history: reach for this only when a
+    /// structural, id-ordered enumeration is what's wanted, not a time-respecting one.
+    ///
+    /// Returns:
+    ///     VertexIterator: this vertex, then the reaching vertices in the id-driven order above.
+    pub fn id_ordered_ancestors(&self) -> PyVertexIterator {
+        let iter = self.vertex.in_neighbours().id_ordered_ancestors().iter();
+        (Box::new(iter) as Box<dyn Iterator<Item = VertexView<DynamicGraph>> + Send>).into()
+    }
+
+    /// This vertex, then every vertex it can reach by at least one successor edge, enumerated
+    /// lazily by vertex *id* in the same largest-discovered-id-first, not globally sorted, order
+    /// as [`id_ordered_ancestors`](Self::id_ordered_ancestors). Backed directly by
+    /// [`PathFromVertex::id_ordered_descendants`]. The mirror image of
+    /// [`id_ordered_ancestors`](Self::id_ordered_ancestors); see its docs for why this isn't the
+    /// same thing as [`temporal_descendants`](Self::temporal_descendants)/
+    /// [`descendants`](Self::descendants).
+    ///
+    /// Returns:
+    ///     VertexIterator: this vertex, then the reachable vertices in the id-driven order above.
+    pub fn id_ordered_descendants(&self) -> PyVertexIterator {
+        let iter = self.vertex.out_neighbours().id_ordered_descendants().iter();
+        (Box::new(iter) as Box<dyn Iterator<Item = VertexView<DynamicGraph>> + Send>).into()
+    }
+
+    /// Vertices reachable from this one by a causal path: a walk that only crosses an edge whose
+    /// timestamp is strictly later than the arrival time recorded for the vertex it leaves from.
+    /// Unlike [`temporal_descendants`](Self::temporal_descendants) this is a lazy walk (nothing
+    /// happens until the result is iterated) and the edge-time comparison is strict, so an edge
+    /// active at exactly the arrival time does not extend the path.
+    ///
+    /// Returns:
+    ///     CausalPath: the causally reachable vertices.
+    pub fn descendants(&self) -> PyCausalPath {
+        PyCausalPath {
+            roots: causal_root(&self.vertex, true).into_iter().collect(),
+            descendants: true,
+        }
+    }
+
+    /// Vertices that can reach this one by a causal path: a walk that only crosses an edge whose
+    /// timestamp is strictly earlier than the arrival time recorded for the vertex it leaves
+    /// from. The mirror image of [`descendants`](Self::descendants).
+    ///
+    /// Returns:
+    ///     CausalPath: the causally reaching vertices.
+    pub fn ancestors(&self) -> PyCausalPath {
+        PyCausalPath {
+            roots: causal_root(&self.vertex, false).into_iter().collect(),
+            descendants: false,
+        }
+    }
+
+    /// The cheapest path from this vertex to `target`, by Dijkstra's algorithm over the numeric
+    /// edge property `weight` as edge cost (an edge missing `weight` costs `1.0`, so an unweighted
+    /// graph behaves like plain hop-count shortest path). Only edges visible in the active
+    /// temporal window are considered.
+    ///
+    /// Arguments:
+    ///     target (Vertex): the vertex to find a path to.
+    ///     weight (str): the edge property used as edge cost.
+    ///     direction (str): One of `"out"`, `"in"` or `"both"`. Defaults to `"out"`.
+    ///
+    /// Returns:
+    ///     Optional[ShortestPath]: the cheapest path and its total cost, or `None` if `target`
+    ///     isn't reachable.
+    #[pyo3(signature = (target, weight, direction="out"))]
+    pub fn shortest_path(
+        &self,
+        target: &PyVertex,
+        weight: &str,
+        direction: &str,
+    ) -> PyResult<Option<PyShortestPath>> {
+        let found =
+            dijkstra_shortest_path(vec![self.vertex.clone()], target.vertex.id(), weight, direction)?;
+        Ok(found.map(|(path, cost)| PyShortestPath {
+            vertices: path.into_iter().map(PyVertex::from).collect(),
+            cost,
+        }))
+    }
+
+    /// A* search from this vertex to `target`, using `heuristic` as a lower-bound estimate of the
+    /// remaining cost from each candidate vertex to `target`. Behaves like
+    /// [`shortest_path`](Self::shortest_path) but explores cheaper-looking vertices first,
+    /// typically settling `target` after visiting far fewer vertices when `heuristic` is
+    /// informative.
+    ///
+    /// Arguments:
+    ///     target (Vertex): the vertex to find a path to.
+    ///     weight (str): the edge property used as edge cost.
+    ///     heuristic (Callable[[Vertex], float]): a non-negative lower bound on the remaining cost
+    ///         from a vertex to `target`.
+    ///     direction (str): One of `"out"`, `"in"` or `"both"`. Defaults to `"out"`.
+    ///     admissible (bool): whether `heuristic` never overestimates the true remaining cost. If
+    ///         `True`, the search returns as soon as `target` is first settled. If `False`, it
+    ///         keeps exploring until the open set is empty and returns the cheapest cost found
+    ///         for `target`, to guard against a heuristic that could otherwise cause a suboptimal
+    ///         path to be returned.
+    ///
+    /// Returns:
+    ///     Optional[ShortestPath]: the cheapest path found and its total cost, or `None` if
+    ///     `target` isn't reachable.
+    #[pyo3(signature = (target, weight, heuristic, direction="out", admissible=true))]
+    pub fn astar(
+        &self,
+        target: &PyVertex,
+        weight: &str,
+        heuristic: PyObject,
+        direction: &str,
+        admissible: bool,
+    ) -> PyResult<Option<PyShortestPath>> {
+        let found = astar_search(
+            self.vertex.clone(),
+            target.vertex.id(),
+            weight,
+            heuristic,
+            direction,
+            admissible,
+        )?;
+        Ok(found.map(|(path, cost)| PyShortestPath {
+            vertices: path.into_iter().map(PyVertex::from).collect(),
+            cost,
+        }))
+    }
+
     //******  Python  ******//
     pub fn __getitem__(&self, name: &str) -> PyResult<Prop> {
         self.vertex
@@ -301,6 +511,571 @@ impl PyVertex {
     }
 }
 
+/// Shared BFS core for [`PyVertex::temporal_descendants`]/[`PyVertex::temporal_ancestors`]:
+/// walks forward scanning out-edges for the smallest activation at or after the current arrival
+/// time, or backward scanning in-edges for the largest activation at or before it, relaxing a
+/// vertex's recorded arrival whenever a better one is found (mirroring Dijkstra's stale-entry
+/// handling, but over arrival time rather than cost).
+fn temporal_walk(
+    root: &VertexView<DynamicGraph>,
+    t0: i64,
+    max_hops: Option<usize>,
+    descendants: bool,
+) -> Vec<(VertexView<DynamicGraph>, i64)> {
+    let mut best: HashMap<u64, (VertexView<DynamicGraph>, i64, usize)> = HashMap::new();
+    best.insert(root.id(), (root.clone(), t0, 0));
+
+    let mut order: Vec<u64> = vec![root.id()];
+    let mut queue: VecDeque<u64> = VecDeque::new();
+    queue.push_back(root.id());
+
+    while let Some(id) = queue.pop_front() {
+        let (vertex, t, hops) = best.get(&id).cloned().unwrap();
+        if max_hops.is_some_and(|max| hops >= max) {
+            continue;
+        }
+
+        let edges: Vec<_> = if descendants {
+            vertex.out_edges().collect()
+        } else {
+            vertex.in_edges().collect()
+        };
+        for edge in edges {
+            let neighbour = if descendants { edge.dst() } else { edge.src() };
+            let candidate = if descendants {
+                edge.history().into_iter().filter(|&te| te >= t).min()
+            } else {
+                edge.history().into_iter().filter(|&te| te <= t).max()
+            };
+            let Some(te) = candidate else { continue };
+
+            let is_better = match best.get(&neighbour.id()) {
+                None => true,
+                Some((_, best_t, _)) => {
+                    if descendants {
+                        te < *best_t
+                    } else {
+                        te > *best_t
+                    }
+                }
+            };
+            if is_better {
+                if !best.contains_key(&neighbour.id()) {
+                    order.push(neighbour.id());
+                }
+                best.insert(neighbour.id(), (neighbour.clone(), te, hops + 1));
+                queue.push_back(neighbour.id());
+            }
+        }
+    }
+
+    order
+        .into_iter()
+        .filter_map(|id| best.remove(&id).map(|(v, t, _)| (v, t)))
+        .collect()
+}
+
+/// `vertex`'s own reference time for a causal walk: the earliest time it exists, for a forward
+/// (descendants) walk, or the latest, for a backward (ancestors) walk. `None` if the vertex has
+/// no recorded events to start from.
+fn causal_root(
+    vertex: &VertexView<DynamicGraph>,
+    descendants: bool,
+) -> Option<(VertexView<DynamicGraph>, i64)> {
+    let t0 = if descendants {
+        vertex.earliest_time()
+    } else {
+        vertex.latest_time()
+    }?;
+    Some((vertex.clone(), t0))
+}
+
+/// A min-heap entry for [`CausalIter`], ordered by ascending `key` (reversed so [`BinaryHeap`], a
+/// max-heap, pops the most admissible entry first). `key` is `t` for a forward (descendants) walk
+/// or `-t` for a backward (ancestors) walk, so one ascending ordering covers both directions.
+/// Mirrors [`HeapEntry`]'s decrease-key-by-reinsertion: a popped entry whose `key` no longer
+/// matches the vertex's recorded best is a stale duplicate and is skipped.
+struct CausalHeapEntry {
+    key: i64,
+    id: u64,
+}
+
+impl PartialEq for CausalHeapEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl Eq for CausalHeapEntry {}
+
+impl PartialOrd for CausalHeapEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CausalHeapEntry {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        other.key.cmp(&self.key)
+    }
+}
+
+/// The lazy walk backing [`PyCausalPath`]: like [`temporal_walk`] but strict (an edge active at
+/// exactly a vertex's arrival time does not extend the path) and driven one vertex at a time
+/// through `Iterator::next` rather than computed up front. `best` tracks the most admissible
+/// arrival time found so far for each vertex reached; `heap` orders the frontier by that time so a
+/// vertex is only ever yielded once its true most-admissible arrival has been found, the same way
+/// [`dijkstra_shortest_path`] settles vertices by ascending cost rather than discovery order. A
+/// plain FIFO frontier with settle-once semantics is unsound here: a neighbour can be reached
+/// first via a worse arrival time and settled before a better one (found later through a
+/// different, shorter path) ever gets a chance to relax it.
+struct CausalIter {
+    best: HashMap<u64, (VertexView<DynamicGraph>, i64)>,
+    heap: BinaryHeap<CausalHeapEntry>,
+    descendants: bool,
+}
+
+impl CausalIter {
+    fn new(roots: Vec<(VertexView<DynamicGraph>, i64)>, descendants: bool) -> Self {
+        let mut best = HashMap::new();
+        let mut heap = BinaryHeap::new();
+        for (vertex, t0) in roots {
+            let id = vertex.id();
+            heap.push(CausalHeapEntry {
+                key: Self::key(descendants, t0),
+                id,
+            });
+            best.insert(id, (vertex, t0));
+        }
+        Self {
+            best,
+            heap,
+            descendants,
+        }
+    }
+
+    /// The heap ordering key for an arrival time `t`: ascending on `t` itself for a forward walk,
+    /// ascending on `-t` (i.e. descending on `t`) for a backward walk, so "most admissible first"
+    /// is always "smallest key first" regardless of direction.
+    fn key(descendants: bool, t: i64) -> i64 {
+        if descendants {
+            t
+        } else {
+            -t
+        }
+    }
+
+    /// Relaxes `neighbour`'s recorded arrival time to `te` if it improves on (or introduces) its
+    /// current best, queuing it for expansion.
+    fn relax(&mut self, neighbour: VertexView<DynamicGraph>, te: i64) {
+        let id = neighbour.id();
+        let is_better = match self.best.get(&id) {
+            None => true,
+            Some((_, best_t)) => {
+                if self.descendants {
+                    te < *best_t
+                } else {
+                    te > *best_t
+                }
+            }
+        };
+        if is_better {
+            self.best.insert(id, (neighbour, te));
+            self.heap.push(CausalHeapEntry {
+                key: Self::key(self.descendants, te),
+                id,
+            });
+        }
+    }
+}
+
+impl Iterator for CausalIter {
+    type Item = (VertexView<DynamicGraph>, i64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let CausalHeapEntry { key, id } = self.heap.pop()?;
+            let (vertex, t) = self.best.get(&id).cloned().unwrap();
+            if key != Self::key(self.descendants, t) {
+                // Stale entry: a more admissible arrival time for `id` was already found and
+                // pushed after this one.
+                continue;
+            }
+
+            let edges: Vec<_> = if self.descendants {
+                vertex.out_edges().collect()
+            } else {
+                vertex.in_edges().collect()
+            };
+            for edge in edges {
+                let neighbour = if self.descendants { edge.dst() } else { edge.src() };
+                let candidate = if self.descendants {
+                    edge.history().into_iter().filter(|&te| te > t).min()
+                } else {
+                    edge.history().into_iter().filter(|&te| te < t).max()
+                };
+                if let Some(te) = candidate {
+                    self.relax(neighbour, te);
+                }
+            }
+            return Some((vertex, t));
+        }
+    }
+}
+
+/// A traversal direction shared by the BFS/DFS walk and the neighbour-expansion helpers below.
+#[derive(Clone, Copy)]
+enum TraversalDirection {
+    Out,
+    In,
+    Both,
+}
+
+impl TraversalDirection {
+    fn parse(direction: &str) -> PyResult<Self> {
+        match direction {
+            "out" => Ok(Self::Out),
+            "in" => Ok(Self::In),
+            "both" => Ok(Self::Both),
+            other => Err(PyValueError::new_err(format!(
+                "unknown direction '{other}', expected 'out', 'in' or 'both'"
+            ))),
+        }
+    }
+
+    /// The one-hop [`Operation`] this direction walks with, shared by every root passed to
+    /// [`traversal_iterator`] — all roots here belong to the same graph, so any one of them can
+    /// hand out the `op`.
+    fn op(&self, v: &VertexView<DynamicGraph>) -> Operation<'static> {
+        match self {
+            Self::Out => v.out_neighbours().op,
+            Self::In => v.in_neighbours().op,
+            Self::Both => v.neighbours().op,
+        }
+    }
+}
+
+/// A min-heap entry for [`dijkstra_shortest_path`], ordered by ascending `cost` (reversed so
+/// [`BinaryHeap`], a max-heap, pops the cheapest entry first). `best_cost` handles decrease-key by
+/// simply pushing a new, cheaper entry for a vertex rather than mutating the heap in place; a
+/// popped entry whose `cost` no longer matches `best_cost` is a stale duplicate and is skipped.
+struct HeapEntry {
+    cost: f64,
+    id: u64,
+}
+
+impl PartialEq for HeapEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.cost == other.cost
+    }
+}
+
+impl Eq for HeapEntry {}
+
+impl PartialOrd for HeapEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for HeapEntry {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        other.cost.partial_cmp(&self.cost).unwrap_or(std::cmp::Ordering::Equal)
+    }
+}
+
+/// Dijkstra's algorithm from `roots` (each starting at cost `0.0`) to `target_id`, using the
+/// numeric edge property `weight` as edge cost (falling back to a hop cost of `1.0` when the
+/// property is absent or non-numeric on a given edge). Returns the reconstructed path and its
+/// total cost, or `None` if `target_id` isn't reachable.
+fn dijkstra_shortest_path(
+    roots: Vec<VertexView<DynamicGraph>>,
+    target_id: u64,
+    weight: &str,
+    direction: &str,
+) -> PyResult<Option<(Vec<VertexView<DynamicGraph>>, f64)>> {
+    TraversalDirection::parse(direction)?;
+
+    let mut best_cost: HashMap<u64, f64> = HashMap::new();
+    let mut vertex_by_id: HashMap<u64, VertexView<DynamicGraph>> = HashMap::new();
+    let mut came_from: HashMap<u64, u64> = HashMap::new();
+    let mut heap: BinaryHeap<HeapEntry> = BinaryHeap::new();
+
+    for root in roots {
+        let id = root.id();
+        if best_cost.get(&id).map_or(true, |&c| 0.0 < c) {
+            best_cost.insert(id, 0.0);
+            heap.push(HeapEntry { cost: 0.0, id });
+        }
+        vertex_by_id.insert(id, root);
+    }
+
+    while let Some(HeapEntry { cost, id }) = heap.pop() {
+        if cost > best_cost[&id] {
+            continue;
+        }
+        if id == target_id {
+            return Ok(Some((
+                reconstruct_path(&vertex_by_id, &came_from, target_id),
+                cost,
+            )));
+        }
+
+        let vertex = vertex_by_id[&id].clone();
+        let edges: Vec<_> = match direction {
+            "out" => vertex.out_edges().collect(),
+            "in" => vertex.in_edges().collect(),
+            "both" => vertex.edges().collect(),
+            other => {
+                return Err(PyValueError::new_err(format!(
+                    "unknown direction '{other}', expected 'out', 'in' or 'both'"
+                )))
+            }
+        };
+        for edge in edges {
+            let neighbour = if edge.src().id() == id {
+                edge.dst()
+            } else {
+                edge.src()
+            };
+            let edge_cost = edge
+                .properties()
+                .get(weight)
+                .and_then(|p| p.into_f64())
+                .unwrap_or(1.0);
+            let next_cost = cost + edge_cost;
+            let nid = neighbour.id();
+            if best_cost.get(&nid).map_or(true, |&c| next_cost < c) {
+                best_cost.insert(nid, next_cost);
+                came_from.insert(nid, id);
+                vertex_by_id.insert(nid, neighbour);
+                heap.push(HeapEntry {
+                    cost: next_cost,
+                    id: nid,
+                });
+            }
+        }
+    }
+
+    Ok(None)
+}
+
+/// Walks `vertex_by_id`/`came_from` back from `target_id` to a root, then reverses it into
+/// start-to-target order. Shared by [`dijkstra_shortest_path`] and [`astar_search`].
+fn reconstruct_path(
+    vertex_by_id: &HashMap<u64, VertexView<DynamicGraph>>,
+    came_from: &HashMap<u64, u64>,
+    target_id: u64,
+) -> Vec<VertexView<DynamicGraph>> {
+    let mut path = vec![vertex_by_id[&target_id].clone()];
+    let mut cur = target_id;
+    while let Some(&prev) = came_from.get(&cur) {
+        path.push(vertex_by_id[&prev].clone());
+        cur = prev;
+    }
+    path.reverse();
+    path
+}
+
+/// A* search from `root` to `target_id`: an open set ordered by `g + h` (a [`HeapEntry`] with
+/// `cost` set to that sum), `g_score` tracking the best known cost to reach each vertex and
+/// `h_score` caching each vertex's heuristic value (computed once, since `heuristic` is a pure
+/// function of the vertex), and `came_from` recording the best predecessor seen so far.
+///
+/// When `admissible` is true, the search returns as soon as `target_id` is popped — standard A*,
+/// valid only because an admissible, non-overestimating heuristic guarantees the first time a
+/// vertex is popped is with its optimal cost. When `admissible` is false, that guarantee doesn't
+/// hold, so the search instead keeps draining the open set and returns the cheapest cost recorded
+/// for `target_id` once no further vertex remains to explore.
+fn astar_search(
+    root: VertexView<DynamicGraph>,
+    target_id: u64,
+    weight: &str,
+    heuristic: PyObject,
+    direction: &str,
+    admissible: bool,
+) -> PyResult<Option<(Vec<VertexView<DynamicGraph>>, f64)>> {
+    TraversalDirection::parse(direction)?;
+
+    Python::with_gil(|py| {
+        let mut g_score: HashMap<u64, f64> = HashMap::new();
+        let mut h_score: HashMap<u64, f64> = HashMap::new();
+        let mut vertex_by_id: HashMap<u64, VertexView<DynamicGraph>> = HashMap::new();
+        let mut came_from: HashMap<u64, u64> = HashMap::new();
+        let mut open: BinaryHeap<HeapEntry> = BinaryHeap::new();
+
+        let root_id = root.id();
+        let h0: f64 = heuristic
+            .call1(py, (PyVertex::from(root.clone()),))?
+            .extract(py)?;
+        g_score.insert(root_id, 0.0);
+        h_score.insert(root_id, h0);
+        vertex_by_id.insert(root_id, root);
+        open.push(HeapEntry {
+            cost: h0,
+            id: root_id,
+        });
+
+        let mut best_target_cost: Option<f64> = None;
+
+        while let Some(HeapEntry { cost: f, id }) = open.pop() {
+            if f > g_score[&id] + h_score[&id] {
+                continue; // stale: a cheaper entry for this vertex has already been processed
+            }
+            if id == target_id {
+                if admissible {
+                    return Ok(Some((
+                        reconstruct_path(&vertex_by_id, &came_from, target_id),
+                        g_score[&target_id],
+                    )));
+                }
+                best_target_cost = Some(g_score[&target_id]);
+                continue;
+            }
+
+            let vertex = vertex_by_id[&id].clone();
+            let edges: Vec<_> = match direction {
+                "out" => vertex.out_edges().collect(),
+                "in" => vertex.in_edges().collect(),
+                "both" => vertex.edges().collect(),
+                other => {
+                    return Err(PyValueError::new_err(format!(
+                        "unknown direction '{other}', expected 'out', 'in' or 'both'"
+                    )))
+                }
+            };
+            for edge in edges {
+                let neighbour = if edge.src().id() == id {
+                    edge.dst()
+                } else {
+                    edge.src()
+                };
+                let edge_cost = edge
+                    .properties()
+                    .get(weight)
+                    .and_then(|p| p.into_f64())
+                    .unwrap_or(1.0);
+                let tentative_g = g_score[&id] + edge_cost;
+                let nid = neighbour.id();
+                if g_score.get(&nid).map_or(true, |&g| tentative_g < g) {
+                    g_score.insert(nid, tentative_g);
+                    came_from.insert(nid, id);
+                    if !h_score.contains_key(&nid) {
+                        let h: f64 = heuristic
+                            .call1(py, (PyVertex::from(neighbour.clone()),))?
+                            .extract(py)?;
+                        h_score.insert(nid, h);
+                    }
+                    vertex_by_id.insert(nid, neighbour);
+                    open.push(HeapEntry {
+                        cost: tentative_g + h_score[&nid],
+                        id: nid,
+                    });
+                }
+            }
+        }
+
+        Ok(best_target_cost
+            .map(|cost| (reconstruct_path(&vertex_by_id, &came_from, target_id), cost)))
+    })
+}
+
+/// Builds the lazy [`PyVertexIterator`] shared by [`PyVertex::bfs`]/[`PyVertex::dfs`] and
+/// [`PyPathFromVertex::bfs`]/[`PyPathFromVertex::dfs`], seeded with `roots` at depth 0 and
+/// delegating the walk itself to [`bounded_walk_from_roots`] (`db/graph/path.rs`) — the same
+/// visited-set-bounded walk that backs [`PathFromVertex::bfs`]/[`PathFromGraph::bfs`], rather than
+/// a second, independent BFS/DFS implementation living only here.
+fn traversal_iterator(
+    roots: Vec<VertexView<DynamicGraph>>,
+    max_depth: Option<usize>,
+    direction: &str,
+    breadth_first: bool,
+) -> PyResult<PyVertexIterator> {
+    let direction = TraversalDirection::parse(direction)?;
+    let Some(first) = roots.first() else {
+        return Ok((Box::new(std::iter::empty())
+            as Box<dyn Iterator<Item = VertexView<DynamicGraph>> + Send>)
+            .into());
+    };
+    let graph = first.graph.clone();
+    let op: Operation<'static> = direction.op(first);
+    let root_ids: Vec<_> = roots.iter().map(|v| v.vertex).collect();
+    let walked = bounded_walk_from_roots(op, root_ids, max_depth.unwrap_or(usize::MAX), breadth_first);
+    let iter = walked.map(move |vertex| VertexView {
+        graph: graph.clone(),
+        vertex,
+    });
+    Ok((Box::new(iter) as Box<dyn Iterator<Item = VertexView<DynamicGraph>> + Send>).into())
+}
+
+/// Shared core for [`PyPathFromVertex::expand`]/[`PyPathFromGraph::expand`]: runs `hops` rounds
+/// of neighbour expansion from `roots`, checking `edge_filter` against each candidate edge before
+/// crossing it and `vertex_filter` against each landing vertex before it joins the next frontier.
+/// A vertex already reached earlier in the walk is not revisited or re-yielded.
+fn expand_from(
+    roots: Vec<VertexView<DynamicGraph>>,
+    hops: usize,
+    direction: &str,
+    edge_filter: Option<PyObject>,
+    vertex_filter: Option<PyObject>,
+) -> PyResult<Vec<VertexView<DynamicGraph>>> {
+    Python::with_gil(|py| {
+        let mut visited: HashSet<u64> = roots.iter().map(|v| v.id()).collect();
+        let mut frontier = roots;
+        let mut reached = Vec::new();
+
+        for _ in 0..hops {
+            if frontier.is_empty() {
+                break;
+            }
+            let mut next_frontier = Vec::new();
+            for vertex in &frontier {
+                let edges: Vec<_> = match direction {
+                    "out" => vertex.out_edges().collect(),
+                    "in" => vertex.in_edges().collect(),
+                    "both" => vertex.edges().collect(),
+                    other => {
+                        return Err(PyValueError::new_err(format!(
+                            "unknown direction '{other}', expected 'out', 'in' or 'both'"
+                        )))
+                    }
+                };
+                for edge in edges {
+                    if let Some(filter) = &edge_filter {
+                        let keep: bool =
+                            filter.call1(py, (PyEdge::from(edge.clone()),))?.extract(py)?;
+                        if !keep {
+                            continue;
+                        }
+                    }
+
+                    let neighbour = if edge.src().id() == vertex.id() {
+                        edge.dst()
+                    } else {
+                        edge.src()
+                    };
+                    if let Some(filter) = &vertex_filter {
+                        let keep: bool = filter
+                            .call1(py, (PyVertex::from(neighbour.clone()),))?
+                            .extract(py)?;
+                        if !keep {
+                            continue;
+                        }
+                    }
+
+                    if visited.insert(neighbour.id()) {
+                        reached.push(neighbour.clone());
+                        next_frontier.push(neighbour);
+                    }
+                }
+            }
+            frontier = next_frontier;
+        }
+
+        Ok(reached)
+    })
+}
+
 impl_timeops!(PyVertex, vertex, VertexView<DynamicGraph>, "vertex");
 
 impl Repr for PyVertex {
@@ -604,6 +1379,172 @@ impl PyVertices {
     fn collect(&self) -> Vec<PyVertex> {
         self.__iter__().into_iter().collect()
     }
+
+    /// Applies a Python callable to each vertex's value of `src_name` and stores the result as a
+    /// new constant property `dst_name`. Runs as a single pass over `self`, avoiding the
+    /// per-element GIL churn of looping over `__getitem__`/`add_constant_properties` in Python.
+    ///
+    /// Arguments:
+    ///     src_name (str): the property to read.
+    ///     func (Callable[[Prop], Prop]): applied to each vertex's `src_name` value.
+    ///     dst_name (str): the property the callable's result is stored under.
+    ///
+    /// Returns:
+    ///     Vertices: `self`, for chaining.
+    pub fn map_properties(
+        &self,
+        src_name: &str,
+        func: PyObject,
+        dst_name: &str,
+    ) -> PyResult<PyVertices> {
+        Python::with_gil(|py| -> PyResult<()> {
+            for v in self.vertices.iter() {
+                let Some(value) = v.properties().get(src_name) else {
+                    continue;
+                };
+                let mapped: Prop = func.call1(py, (value,))?.extract(py)?;
+                v.add_constant_properties(HashMap::from([(dst_name.to_string(), mapped)]))
+                    .map_err(|e| PyValueError::new_err(e.to_string()))?;
+            }
+            Ok(())
+        })?;
+        Ok(PyVertices {
+            vertices: self.vertices.clone(),
+        })
+    }
+
+    /// Packs several scalar properties into a single list-valued constant property `dst_name`.
+    ///
+    /// Arguments:
+    ///     names (List[str]): the properties to pack, in order. Vertices missing one are packed
+    ///         with the remaining values only.
+    ///     dst_name (str): the property the packed list is stored under.
+    ///
+    /// Returns:
+    ///     Vertices: `self`, for chaining.
+    pub fn group_properties(&self, names: Vec<String>, dst_name: &str) -> PyResult<PyVertices> {
+        for v in self.vertices.iter() {
+            let values: Vec<Prop> = names
+                .iter()
+                .filter_map(|name| v.properties().get(name))
+                .collect();
+            v.add_constant_properties(HashMap::from([(
+                dst_name.to_string(),
+                Prop::List(values.into()),
+            )]))
+            .map_err(|e| PyValueError::new_err(e.to_string()))?;
+        }
+        Ok(PyVertices {
+            vertices: self.vertices.clone(),
+        })
+    }
+
+    /// The inverse of [`group_properties`](Self::group_properties): unpacks the list-valued
+    /// property `src_name` back into one scalar constant property per name in `names`, in order.
+    ///
+    /// Returns:
+    ///     Vertices: `self`, for chaining.
+    pub fn ungroup_properties(&self, src_name: &str, names: Vec<String>) -> PyResult<PyVertices> {
+        for v in self.vertices.iter() {
+            let Some(Prop::List(values)) = v.properties().get(src_name) else {
+                continue;
+            };
+            for (name, value) in names.iter().zip(values.iter()) {
+                v.add_constant_properties(HashMap::from([(name.clone(), value.clone())]))
+                    .map_err(|e| PyValueError::new_err(e.to_string()))?;
+            }
+        }
+        Ok(PyVertices {
+            vertices: self.vertices.clone(),
+        })
+    }
+
+    /// Spreads a marked property value across neighbours for a fixed number of rounds, writing
+    /// the round each vertex became infected back as the constant property
+    /// `"{property}_infected_round"`.
+    ///
+    /// A vertex is seeded if `property` currently holds `value`. Each subsequent round infects
+    /// every not-yet-infected vertex with at least one already-infected neighbour (in the given
+    /// `direction`), until `rounds` rounds have run or no new vertex was infected.
+    ///
+    /// Arguments:
+    ///     property (str): the property read to find the seed vertices.
+    ///     value (Prop): the value that marks a vertex as seeded.
+    ///     rounds (Optional[int]): number of propagation rounds; `None` iterates to a fixed point.
+    ///     direction (str): One of `"out"`, `"in"` or `"both"`. Defaults to `"in"`.
+    ///
+    /// Returns:
+    ///     The round each vertex became infected, or `None` if it never was, aligned with `self`.
+    #[pyo3(signature = (property, value, rounds=None, direction="in"))]
+    pub fn infect_vertex_property(
+        &self,
+        property: &str,
+        value: Prop,
+        rounds: Option<usize>,
+        direction: &str,
+    ) -> PyResult<Vec<Option<usize>>> {
+        let vertices: Vec<VertexView<DynamicGraph>> = self.vertices.iter().collect();
+        let neighbours_of = |v: &VertexView<DynamicGraph>| -> PyResult<Vec<VertexView<DynamicGraph>>> {
+            match direction {
+                "out" => Ok(v.out_neighbours().iter().collect()),
+                "in" => Ok(v.in_neighbours().iter().collect()),
+                "both" => Ok(v.neighbours().iter().collect()),
+                other => Err(PyValueError::new_err(format!(
+                    "unknown direction '{other}', expected 'out', 'in' or 'both'"
+                ))),
+            }
+        };
+
+        let mut infected_round: HashMap<u64, usize> = HashMap::new();
+        for v in &vertices {
+            if v.properties().get(property).as_ref() == Some(&value) {
+                infected_round.insert(v.id(), 0);
+            }
+        }
+
+        let mut round = 0;
+        loop {
+            if rounds.is_some_and(|max| round >= max) {
+                break;
+            }
+            let mut newly_infected = Vec::new();
+            for v in &vertices {
+                if infected_round.contains_key(&v.id()) {
+                    continue;
+                }
+                let spread = neighbours_of(v)?
+                    .into_iter()
+                    .any(|n| infected_round.contains_key(&n.id()));
+                if spread {
+                    newly_infected.push(v.id());
+                }
+            }
+            if newly_infected.is_empty() {
+                break;
+            }
+            round += 1;
+            for id in newly_infected {
+                infected_round.insert(id, round);
+            }
+        }
+
+        let result_property = format!("{property}_infected_round");
+        for v in &vertices {
+            if let Some(&round) = infected_round.get(&v.id()) {
+                v.add_constant_properties(HashMap::from([(
+                    result_property.clone(),
+                    Prop::I64(round as i64),
+                )]))
+                .map_err(|e| PyValueError::new_err(e.to_string()))?;
+            }
+        }
+
+        Ok(vertices
+            .iter()
+            .map(|v| infected_round.get(&v.id()).copied())
+            .collect())
+    }
+
     #[doc = default_layer_doc_string!()]
     pub fn default_layer(&self) -> PyVertices {
         self.vertices.default_layer().into()
@@ -739,6 +1680,43 @@ impl PyPathFromGraph {
         self.path.neighbours().into()
     }
 
+    /// As [`PyPathFromVertex::expand`], applied independently to the path rooted at each
+    /// starting vertex, so `.collect()` yields one reached-vertex list per starting vertex.
+    ///
+    /// Arguments:
+    ///     hops (int): number of further hops to expand by.
+    ///     direction (str): One of `"out"`, `"in"` or `"both"`. Defaults to `"out"`.
+    ///     edge_filter (Optional[Callable[[Edge], bool]]): checked against each edge as the walk
+    ///         crosses it; edges it rejects are not traversed.
+    ///     vertex_filter (Optional[Callable[[Vertex], bool]]): checked against each landing
+    ///         vertex before the next hop; vertices it rejects are pruned.
+    ///
+    /// Returns:
+    ///     One list of reached vertices per starting vertex.
+    #[pyo3(signature = (hops, direction="out", edge_filter=None, vertex_filter=None))]
+    pub fn expand(
+        &self,
+        hops: usize,
+        direction: &str,
+        edge_filter: Option<PyObject>,
+        vertex_filter: Option<PyObject>,
+    ) -> PyResult<Vec<Vec<PyVertex>>> {
+        self.__iter__()
+            .into_iter()
+            .map(|path| {
+                let roots: Vec<VertexView<DynamicGraph>> = path.path.iter().collect();
+                let reached = expand_from(
+                    roots,
+                    hops,
+                    direction,
+                    edge_filter.clone(),
+                    vertex_filter.clone(),
+                )?;
+                Ok(reached.into_iter().map(PyVertex::from).collect())
+            })
+            .collect()
+    }
+
     #[doc = default_layer_doc_string!()]
     pub fn default_layer(&self) -> Self {
         self.path.default_layer().into()
@@ -894,6 +1872,118 @@ impl PyPathFromVertex {
         self.path.neighbours().into()
     }
 
+    /// Extends this path with `hops` further neighbour-expansion steps, guarded by optional
+    /// predicates checked against each candidate edge and landing vertex.
+    ///
+    /// Arguments:
+    ///     hops (int): number of further hops to expand by.
+    ///     direction (str): One of `"out"`, `"in"` or `"both"`. Defaults to `"out"`.
+    ///     edge_filter (Optional[Callable[[Edge], bool]]): checked against each edge as the walk
+    ///         crosses it; edges it rejects are not traversed.
+    ///     vertex_filter (Optional[Callable[[Vertex], bool]]): checked against each landing
+    ///         vertex before the next hop; vertices it rejects are pruned.
+    ///
+    /// Returns:
+    ///     The vertices reached within `hops` filtered hops of this path's current vertices.
+    #[pyo3(signature = (hops, direction="out", edge_filter=None, vertex_filter=None))]
+    pub fn expand(
+        &self,
+        hops: usize,
+        direction: &str,
+        edge_filter: Option<PyObject>,
+        vertex_filter: Option<PyObject>,
+    ) -> PyResult<Vec<PyVertex>> {
+        let roots: Vec<VertexView<DynamicGraph>> = self.path.iter().collect();
+        let reached = expand_from(roots, hops, direction, edge_filter, vertex_filter)?;
+        Ok(reached.into_iter().map(PyVertex::from).collect())
+    }
+
+    /// As [`PyVertex::bfs`], rooted at every vertex currently in this path instead of a single
+    /// vertex.
+    ///
+    /// Arguments:
+    ///     max_depth (Optional[int]): Maximum number of hops to explore.
+    ///     direction (str): One of `"out"`, `"in"` or `"both"`. Defaults to `"out"`.
+    ///
+    /// Returns:
+    ///     VertexIterator: the reachable vertices in discovery order.
+    #[pyo3(signature = (max_depth=None, direction="out"))]
+    pub fn bfs(&self, max_depth: Option<usize>, direction: &str) -> PyResult<PyVertexIterator> {
+        traversal_iterator(self.path.iter().collect(), max_depth, direction, true)
+    }
+
+    /// As [`PyVertex::dfs`], rooted at every vertex currently in this path instead of a single
+    /// vertex.
+    ///
+    /// Arguments:
+    ///     max_depth (Optional[int]): Maximum number of hops to explore.
+    ///     direction (str): One of `"out"`, `"in"` or `"both"`. Defaults to `"out"`.
+    ///
+    /// Returns:
+    ///     VertexIterator: the reachable vertices in discovery order.
+    #[pyo3(signature = (max_depth=None, direction="out"))]
+    pub fn dfs(&self, max_depth: Option<usize>, direction: &str) -> PyResult<PyVertexIterator> {
+        traversal_iterator(self.path.iter().collect(), max_depth, direction, false)
+    }
+
+    /// As [`PyVertex::descendants`], rooted at every vertex currently in this path instead of a
+    /// single vertex.
+    ///
+    /// Returns:
+    ///     CausalPath: the causally reachable vertices.
+    pub fn descendants(&self) -> PyCausalPath {
+        PyCausalPath {
+            roots: self
+                .path
+                .iter()
+                .filter_map(|v| causal_root(&v, true))
+                .collect(),
+            descendants: true,
+        }
+    }
+
+    /// As [`PyVertex::ancestors`], rooted at every vertex currently in this path instead of a
+    /// single vertex.
+    ///
+    /// Returns:
+    ///     CausalPath: the causally reaching vertices.
+    pub fn ancestors(&self) -> PyCausalPath {
+        PyCausalPath {
+            roots: self
+                .path
+                .iter()
+                .filter_map(|v| causal_root(&v, false))
+                .collect(),
+            descendants: false,
+        }
+    }
+
+    /// As [`PyVertex::shortest_path`], starting from whichever vertex currently in this path
+    /// reaches `target` most cheaply.
+    ///
+    /// Arguments:
+    ///     target (Vertex): the vertex to find a path to.
+    ///     weight (str): the edge property used as edge cost.
+    ///     direction (str): One of `"out"`, `"in"` or `"both"`. Defaults to `"out"`.
+    ///
+    /// Returns:
+    ///     Optional[ShortestPath]: the cheapest path and its total cost, or `None` if `target`
+    ///     isn't reachable from any vertex in this path.
+    #[pyo3(signature = (target, weight, direction="out"))]
+    pub fn shortest_path(
+        &self,
+        target: &PyVertex,
+        weight: &str,
+        direction: &str,
+    ) -> PyResult<Option<PyShortestPath>> {
+        let roots: Vec<VertexView<DynamicGraph>> = self.path.iter().collect();
+        let found = dijkstra_shortest_path(roots, target.vertex.id(), weight, direction)?;
+        Ok(found.map(|(path, cost)| PyShortestPath {
+            vertices: path.into_iter().map(PyVertex::from).collect(),
+            cost,
+        }))
+    }
+
     pub fn default_layer(&self) -> Self {
         self.path.default_layer().into()
     }
@@ -958,6 +2048,142 @@ impl From<Box<dyn Iterator<Item = PyVertex> + Send>> for PyVertexIterator {
     }
 }
 
+/// The result of a time-respecting walk from a vertex: each reached vertex, annotated with the
+/// arrival time the walk recorded for it.
+///
+/// See [`PyVertex::temporal_descendants`]/[`PyVertex::temporal_ancestors`].
+#[pyclass(name = "TemporalPath")]
+#[derive(Clone)]
+pub struct PyTemporalPath {
+    reached: Vec<(PyVertex, i64)>,
+}
+
+impl From<Vec<(VertexView<DynamicGraph>, i64)>> for PyTemporalPath {
+    fn from(value: Vec<(VertexView<DynamicGraph>, i64)>) -> Self {
+        Self {
+            reached: value.into_iter().map(|(v, t)| (v.into(), t)).collect(),
+        }
+    }
+}
+
+#[pymethods]
+impl PyTemporalPath {
+    fn __iter__(&self) -> PyVertexIterator {
+        let vertices: Vec<PyVertex> = self.reached.iter().map(|(v, _)| v.clone()).collect();
+        Box::new(vertices.into_iter()).into()
+    }
+
+    fn __len__(&self) -> usize {
+        self.reached.len()
+    }
+
+    /// The reached vertices, in the order they were first reached.
+    fn collect(&self) -> Vec<PyVertex> {
+        self.reached.iter().map(|(v, _)| v.clone()).collect()
+    }
+
+    /// The arrival time recorded for each vertex, aligned with `collect()`.
+    fn arrival_times(&self) -> Vec<i64> {
+        self.reached.iter().map(|(_, t)| *t).collect()
+    }
+}
+
+/// A lazy, causal reachability walk: vertices reached by crossing only edges that move strictly
+/// forward (for [`descendants`](PyVertex::descendants)) or strictly backward (for
+/// [`ancestors`](PyVertex::ancestors)) in time relative to the arrival time at each vertex
+/// crossed. Iterating this is what actually drives the walk; constructing it does no work.
+#[pyclass(name = "CausalPath")]
+pub struct PyCausalPath {
+    roots: Vec<(VertexView<DynamicGraph>, i64)>,
+    descendants: bool,
+}
+
+impl PyCausalPath {
+    fn walk(&self) -> CausalIter {
+        CausalIter::new(self.roots.clone(), self.descendants)
+    }
+}
+
+#[pymethods]
+impl PyCausalPath {
+    fn __iter__(&self) -> PyVertexIterator {
+        let iter = self.walk().map(|(v, _)| v);
+        (Box::new(iter) as Box<dyn Iterator<Item = VertexView<DynamicGraph>> + Send>).into()
+    }
+
+    /// The causally reachable vertices, in the order the walk settles them: ascending arrival
+    /// time for descendants, descending arrival time for ancestors.
+    fn collect(&self) -> Vec<PyVertex> {
+        self.__iter__().into_iter().collect()
+    }
+
+    /// Of `ids`, the ones this walk does not reach.
+    ///
+    /// Arguments:
+    ///     ids (Set[int]): candidate vertex ids.
+    ///
+    /// Returns:
+    ///     The subset of `ids` that is not causally reachable from this walk's roots.
+    fn remove_ancestors_from(&self, ids: HashSet<u64>) -> HashSet<u64> {
+        let reached: HashSet<u64> = self.walk().map(|(v, _)| v.id()).collect();
+        ids.into_iter().filter(|id| !reached.contains(id)).collect()
+    }
+
+    /// Vertices reached by both this walk and the equivalent walk rooted at `other`.
+    ///
+    /// Arguments:
+    ///     other (Vertex): the vertex to intersect this walk's reachable set with.
+    ///
+    /// Returns:
+    ///     The vertices causally reachable from both roots.
+    fn common_ancestors(&self, other: &PyVertex) -> Vec<PyVertex> {
+        let mine: HashMap<u64, VertexView<DynamicGraph>> =
+            self.walk().map(|(v, _)| (v.id(), v)).collect();
+        let other_roots = causal_root(&other.vertex, self.descendants)
+            .into_iter()
+            .collect();
+        let theirs: HashSet<u64> = CausalIter::new(other_roots, self.descendants)
+            .map(|(v, _)| v.id())
+            .collect();
+        mine.into_iter()
+            .filter(|(id, _)| theirs.contains(id))
+            .map(|(_, v)| v.into())
+            .collect()
+    }
+}
+
+/// The cheapest path found by [`PyVertex::shortest_path`]/[`PyPathFromVertex::shortest_path`]:
+/// the vertices visited, from start to `target`, and the path's total edge-weighted cost.
+#[pyclass(name = "ShortestPath")]
+#[derive(Clone)]
+pub struct PyShortestPath {
+    vertices: Vec<PyVertex>,
+    cost: f64,
+}
+
+#[pymethods]
+impl PyShortestPath {
+    fn __iter__(&self) -> PyVertexIterator {
+        let vertices = self.vertices.clone();
+        Box::new(vertices.into_iter()).into()
+    }
+
+    fn __len__(&self) -> usize {
+        self.vertices.len()
+    }
+
+    /// The vertices on the path, from start to target.
+    fn collect(&self) -> Vec<PyVertex> {
+        self.vertices.clone()
+    }
+
+    /// The path's total edge-weighted cost.
+    #[getter]
+    fn cost(&self) -> f64 {
+        self.cost
+    }
+}
+
 #[pyclass]
 pub struct PathIterator {
     pub(crate) iter: Box<dyn Iterator<Item = PyPathFromVertex> + Send>,
@@ -1074,6 +2300,69 @@ impl PyVertexIterable {
         let builder = self.builder.clone();
         (move || builder().neighbours()).into()
     }
+
+    /// The number of vertices, streamed rather than collected into a list first.
+    fn count(&self) -> usize {
+        self.builder.clone()().iter().count()
+    }
+
+    /// The sum of every vertex's degree.
+    fn sum_degree(&self) -> usize {
+        self.builder.clone()().iter().map(|v| v.degree()).sum()
+    }
+
+    /// The earliest `earliest_time` across every vertex, or `None` if none have any events.
+    fn min_time(&self) -> Option<i64> {
+        self.builder
+            .clone()()
+        .iter()
+        .filter_map(|v| v.earliest_time())
+        .min()
+    }
+
+    /// The latest `latest_time` across every vertex, or `None` if none have any events.
+    fn max_time(&self) -> Option<i64> {
+        self.builder
+            .clone()()
+        .iter()
+        .filter_map(|v| v.latest_time())
+        .max()
+    }
+
+    /// Reduces the numeric property `name` across every vertex with `op`, streaming rather than
+    /// collecting the vertices into a list first. Vertices missing `name`, or holding a
+    /// non-numeric value for it, are skipped.
+    ///
+    /// Arguments:
+    ///     name (str): the property to read from each vertex.
+    ///     op (str): one of `"sum"`, `"min"`, `"max"` or `"mean"`.
+    ///
+    /// Returns:
+    ///     The reduced value, or `None` if no vertex had a numeric `name` (`"sum"` returns `0.0`
+    ///     in that case instead, matching the empty sum).
+    fn aggregate_property(&self, name: &str, op: &str) -> PyResult<Option<f64>> {
+        let values = self
+            .builder
+            .clone()()
+        .iter()
+        .filter_map(|v| v.properties().get(name).and_then(|p| p.into_f64()));
+        match op {
+            "sum" => Ok(Some(values.sum())),
+            "min" => Ok(values.fold(None, |acc: Option<f64>, x| {
+                Some(acc.map_or(x, |a| a.min(x)))
+            })),
+            "max" => Ok(values.fold(None, |acc: Option<f64>, x| {
+                Some(acc.map_or(x, |a| a.max(x)))
+            })),
+            "mean" => {
+                let (sum, count) = values.fold((0.0, 0usize), |(s, c), x| (s + x, c + 1));
+                Ok((count > 0).then_some(sum / count as f64))
+            }
+            other => Err(PyValueError::new_err(format!(
+                "unknown aggregation op '{other}', expected 'sum', 'min', 'max' or 'mean'"
+            ))),
+        }
+    }
 }
 
 py_nested_iterable!(PyNestedVertexIterable, VertexView<DynamicGraph>);
@@ -1160,4 +2449,654 @@ impl PyNestedVertexIterable {
         let builder = self.builder.clone();
         (move || builder().neighbours()).into()
     }
+
+    /// The number of vertices in each group, streamed rather than collecting any group's vertex
+    /// list first.
+    fn count(&self) -> UsizeIterable {
+        let builder = self.builder.clone();
+        (move || builder().iter().map(|group| group.iter().count())).into()
+    }
+
+    /// The sum of vertex degrees within each group.
+    fn sum_degree(&self) -> UsizeIterable {
+        let builder = self.builder.clone();
+        (move || {
+            builder()
+                .iter()
+                .map(|group| group.iter().map(|v| v.degree()).sum())
+        })
+        .into()
+    }
+
+    /// The earliest `earliest_time` within each group, or `None` for a group with no events.
+    fn min_time(&self) -> OptionI64Iterable {
+        let builder = self.builder.clone();
+        (move || {
+            builder().iter().map(|group| {
+                group
+                    .iter()
+                    .filter_map(|v| v.earliest_time())
+                    .min()
+            })
+        })
+        .into()
+    }
+
+    /// The latest `latest_time` within each group, or `None` for a group with no events.
+    fn max_time(&self) -> OptionI64Iterable {
+        let builder = self.builder.clone();
+        (move || {
+            builder().iter().map(|group| {
+                group
+                    .iter()
+                    .filter_map(|v| v.latest_time())
+                    .max()
+            })
+        })
+        .into()
+    }
+
+    /// As [`PyVertexIterable::aggregate_property`], applied independently within each group.
+    ///
+    /// Arguments:
+    ///     name (str): the property to read from each vertex.
+    ///     op (str): one of `"sum"`, `"min"`, `"max"` or `"mean"`.
+    ///
+    /// Returns:
+    ///     One reduced value per group.
+    fn aggregate_property(&self, name: &str, op: &str) -> PyResult<Vec<Option<f64>>> {
+        self.builder
+            .clone()()
+        .iter()
+        .map(|group| {
+            let values = group
+                .iter()
+                .filter_map(|v| v.properties().get(name).and_then(|p| p.into_f64()));
+            match op {
+                "sum" => Ok(Some(values.sum())),
+                "min" => Ok(values.fold(None, |acc: Option<f64>, x| {
+                    Some(acc.map_or(x, |a| a.min(x)))
+                })),
+                "max" => Ok(values.fold(None, |acc: Option<f64>, x| {
+                    Some(acc.map_or(x, |a| a.max(x)))
+                })),
+                "mean" => {
+                    let (sum, count) = values.fold((0.0, 0usize), |(s, c), x| (s + x, c + 1));
+                    Ok((count > 0).then_some(sum / count as f64))
+                }
+                other => Err(PyValueError::new_err(format!(
+                    "unknown aggregation op '{other}', expected 'sum', 'min', 'max' or 'mean'"
+                ))),
+            }
+        })
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{db::{api::mutation::AdditionOps, graph::graph::Graph}, prelude::*};
+
+    /// `a -> b` at `t=1`, `b -> c` at `t=5`, and `d -> a` at `t=0`, which only leads into `a`.
+    fn chain_graph() -> Graph {
+        let g = Graph::new();
+        g.add_edge(1, "a", "b", NO_PROPS, None).unwrap();
+        g.add_edge(5, "b", "c", NO_PROPS, None).unwrap();
+        g.add_edge(0, "d", "a", NO_PROPS, None).unwrap();
+        g
+    }
+
+    #[test]
+    fn temporal_walk_follows_descendants_forward_in_time() {
+        let g = chain_graph().into_dynamic();
+        let root = g.vertex("a").unwrap();
+
+        let reached = temporal_walk(&root, 0, None, true);
+        let by_name: HashMap<String, i64> =
+            reached.into_iter().map(|(v, t)| (v.name(), t)).collect();
+        assert_eq!(by_name.get("a"), Some(&0));
+        assert_eq!(by_name.get("b"), Some(&1));
+        assert_eq!(by_name.get("c"), Some(&5));
+        // "d" only reaches "a" going backwards, not forwards from "a".
+        assert_eq!(by_name.get("d"), None);
+    }
+
+    #[test]
+    fn temporal_walk_follows_ancestors_backward_in_time() {
+        let g = chain_graph().into_dynamic();
+        let root = g.vertex("a").unwrap();
+
+        let reached = temporal_walk(&root, 10, None, false);
+        let by_name: HashMap<String, i64> =
+            reached.into_iter().map(|(v, t)| (v.name(), t)).collect();
+        assert_eq!(by_name.get("a"), Some(&10));
+        assert_eq!(by_name.get("d"), Some(&0));
+        assert_eq!(by_name.get("b"), None);
+    }
+
+    #[test]
+    fn temporal_walk_respects_max_hops() {
+        let g = chain_graph().into_dynamic();
+        let root = g.vertex("a").unwrap();
+
+        let reached = temporal_walk(&root, 0, Some(1), true);
+        let names: HashSet<String> = reached.into_iter().map(|(v, _)| v.name()).collect();
+        assert_eq!(names, HashSet::from(["a".to_string(), "b".to_string()]));
+    }
+
+    /// `a -> b`, `a -> c`, `b -> d`, `c -> d`, so `d` is reachable via two distinct two-hop paths.
+    fn diamond_graph() -> Graph {
+        let g = Graph::new();
+        g.add_edge(0, "a", "b", NO_PROPS, None).unwrap();
+        g.add_edge(0, "a", "c", NO_PROPS, None).unwrap();
+        g.add_edge(0, "b", "d", NO_PROPS, None).unwrap();
+        g.add_edge(0, "c", "d", NO_PROPS, None).unwrap();
+        g
+    }
+
+    #[test]
+    fn bfs_visits_each_vertex_exactly_once_regardless_of_how_many_paths_reach_it() {
+        let g = diamond_graph().into_dynamic();
+        let root = g.vertex("a").unwrap();
+
+        let iter = traversal_iterator(vec![root], None, "out", true).unwrap();
+        let names: HashSet<String> = iter.into_iter().map(|v| v.name()).collect();
+        assert_eq!(
+            names,
+            HashSet::from(["a".to_string(), "b".to_string(), "c".to_string(), "d".to_string()])
+        );
+    }
+
+    #[test]
+    fn bfs_respects_max_depth() {
+        let g = diamond_graph().into_dynamic();
+        let root = g.vertex("a").unwrap();
+
+        let iter = traversal_iterator(vec![root], Some(1), "out", true).unwrap();
+        let names: HashSet<String> = iter.into_iter().map(|v| v.name()).collect();
+        assert_eq!(names, HashSet::from(["a".to_string(), "b".to_string(), "c".to_string()]));
+    }
+
+    #[test]
+    fn dfs_and_bfs_visit_the_same_vertex_set_but_in_a_different_order() {
+        let g = diamond_graph().into_dynamic();
+
+        let bfs_order: Vec<String> = traversal_iterator(vec![g.vertex("a").unwrap()], None, "out", true)
+            .unwrap()
+            .into_iter()
+            .map(|v| v.name())
+            .collect();
+        let dfs_order: Vec<String> = traversal_iterator(vec![g.vertex("a").unwrap()], None, "out", false)
+            .unwrap()
+            .into_iter()
+            .map(|v| v.name())
+            .collect();
+
+        assert_eq!(bfs_order[0], "a");
+        assert_eq!(dfs_order[0], "a");
+        assert_ne!(bfs_order, dfs_order);
+        let bfs_set: HashSet<&String> = bfs_order.iter().collect();
+        let dfs_set: HashSet<&String> = dfs_order.iter().collect();
+        assert_eq!(bfs_set, dfs_set);
+    }
+
+    #[test]
+    fn traversal_iterator_rejects_an_unknown_direction() {
+        let g = diamond_graph().into_dynamic();
+        let root = g.vertex("a").unwrap();
+        assert!(traversal_iterator(vec![root], None, "sideways", true).is_err());
+    }
+
+    #[test]
+    fn id_ordered_ancestors_yields_the_starting_vertex_first_then_every_predecessor() {
+        let g = diamond_graph().into_dynamic();
+        let py_vertex = PyVertex::from(g.vertex("d").unwrap());
+
+        let mut reached = py_vertex.id_ordered_ancestors().into_iter();
+        assert_eq!(reached.next().map(|v| v.name()), Some("d".to_string()));
+        let rest: HashSet<String> = reached.map(|v| v.name()).collect();
+        assert_eq!(rest, HashSet::from(["b".to_string(), "c".to_string(), "a".to_string()]));
+    }
+
+    #[test]
+    fn id_ordered_descendants_yields_the_starting_vertex_first_then_every_successor() {
+        let g = diamond_graph().into_dynamic();
+        let py_vertex = PyVertex::from(g.vertex("a").unwrap());
+
+        let mut reached = py_vertex.id_ordered_descendants().into_iter();
+        assert_eq!(reached.next().map(|v| v.name()), Some("a".to_string()));
+        let rest: HashSet<String> = reached.map(|v| v.name()).collect();
+        assert_eq!(rest, HashSet::from(["b".to_string(), "c".to_string(), "d".to_string()]));
+    }
+
+    #[test]
+    fn infect_vertex_property_propagates_one_round_per_hop_from_the_seeded_vertex() {
+        let g = chain_graph().into_dynamic();
+        g.vertex("a")
+            .unwrap()
+            .add_constant_properties(HashMap::from([("seed".to_string(), Prop::Bool(true))]))
+            .unwrap();
+        let vertices = PyVertices {
+            vertices: Vertices::new(g.clone()),
+        };
+
+        let result = vertices
+            .infect_vertex_property("seed", Prop::Bool(true), None, "in")
+            .unwrap();
+        let by_name: HashMap<String, Option<usize>> = vertices
+            .vertices
+            .iter()
+            .map(|v| v.name())
+            .zip(result)
+            .collect();
+        assert_eq!(by_name.get("a"), Some(&Some(0)));
+        assert_eq!(by_name.get("b"), Some(&Some(1)));
+        assert_eq!(by_name.get("c"), Some(&Some(2)));
+        // "d" has no in-neighbours (its only edge, "d -> a", points away from it), so it never
+        // gets infected.
+        assert_eq!(by_name.get("d"), Some(&None));
+    }
+
+    #[test]
+    fn infect_vertex_property_stops_after_the_requested_number_of_rounds() {
+        let g = chain_graph().into_dynamic();
+        g.vertex("a")
+            .unwrap()
+            .add_constant_properties(HashMap::from([("seed".to_string(), Prop::Bool(true))]))
+            .unwrap();
+        let vertices = PyVertices {
+            vertices: Vertices::new(g.clone()),
+        };
+
+        let result = vertices
+            .infect_vertex_property("seed", Prop::Bool(true), Some(1), "in")
+            .unwrap();
+        let by_name: HashMap<String, Option<usize>> = vertices
+            .vertices
+            .iter()
+            .map(|v| v.name())
+            .zip(result)
+            .collect();
+        assert_eq!(by_name.get("a"), Some(&Some(0)));
+        assert_eq!(by_name.get("b"), Some(&Some(1)));
+        // "c" is two hops away, beyond the single allotted round.
+        assert_eq!(by_name.get("c"), Some(&None));
+    }
+
+    #[test]
+    fn group_properties_packs_named_scalars_into_one_list_property() {
+        let g = Graph::new();
+        g.add_vertex(0, "a", NO_PROPS).unwrap();
+        let v = g.vertex("a").unwrap();
+        v.add_constant_properties(HashMap::from([
+            ("x".to_string(), Prop::I64(1)),
+            ("y".to_string(), Prop::I64(2)),
+        ]))
+        .unwrap();
+        let vertices = PyVertices {
+            vertices: Vertices::new(g.into_dynamic()),
+        };
+
+        vertices
+            .group_properties(vec!["x".to_string(), "y".to_string()], "xy")
+            .unwrap();
+        let packed = v.properties().get("xy").unwrap();
+        assert_eq!(packed, Prop::List(vec![Prop::I64(1), Prop::I64(2)].into()));
+    }
+
+    #[test]
+    fn ungroup_properties_is_the_inverse_of_group_properties() {
+        let g = Graph::new();
+        g.add_vertex(0, "a", NO_PROPS).unwrap();
+        let v = g.vertex("a").unwrap();
+        v.add_constant_properties(HashMap::from([(
+            "xy".to_string(),
+            Prop::List(vec![Prop::I64(1), Prop::I64(2)].into()),
+        )]))
+        .unwrap();
+        let vertices = PyVertices {
+            vertices: Vertices::new(g.into_dynamic()),
+        };
+
+        vertices
+            .ungroup_properties("xy", vec!["x".to_string(), "y".to_string()])
+            .unwrap();
+        assert_eq!(v.properties().get("x"), Some(Prop::I64(1)));
+        assert_eq!(v.properties().get("y"), Some(Prop::I64(2)));
+    }
+
+    #[test]
+    fn map_properties_applies_a_python_callable_to_each_vertex_and_stores_the_result() {
+        let g = Graph::new();
+        g.add_vertex(0, "a", NO_PROPS).unwrap();
+        let v = g.vertex("a").unwrap();
+        v.add_constant_properties(HashMap::from([(
+            "x".to_string(),
+            Prop::Str("abc".into()),
+        )]))
+        .unwrap();
+        let vertices = PyVertices {
+            vertices: Vertices::new(g.into_dynamic()),
+        };
+
+        Python::with_gil(|py| {
+            let upper: PyObject = py.eval("lambda s: s.upper()", None, None).unwrap().into();
+            vertices.map_properties("x", upper, "shouted").unwrap();
+        });
+
+        assert_eq!(
+            v.properties().get("shouted"),
+            Some(Prop::Str("ABC".into()))
+        );
+    }
+
+    #[test]
+    fn expand_with_no_filters_reaches_everything_within_hops() {
+        let g = diamond_graph().into_dynamic();
+        let roots = vec![g.vertex("a").unwrap()];
+
+        let reached = expand_from(roots, 2, "out", None, None).unwrap();
+        let names: HashSet<String> = reached.into_iter().map(|v| v.name()).collect();
+        assert_eq!(
+            names,
+            HashSet::from(["b".to_string(), "c".to_string(), "d".to_string()])
+        );
+    }
+
+    #[test]
+    fn expand_vertex_filter_prunes_rejected_landing_vertices() {
+        let g = diamond_graph().into_dynamic();
+        let roots = vec![g.vertex("a").unwrap()];
+
+        Python::with_gil(|py| {
+            let only_c: PyObject = py
+                .eval("lambda v: v.name == 'c'", None, None)
+                .unwrap()
+                .into();
+            let reached = expand_from(roots, 2, "out", None, Some(only_c)).unwrap();
+            let names: HashSet<String> = reached.into_iter().map(|v| v.name()).collect();
+            // "b" is pruned immediately, so "d" is never reached through it, and "c" -> "d" is
+            // crossed but "d" fails the filter too.
+            assert_eq!(names, HashSet::from(["c".to_string()]));
+        });
+    }
+
+    #[test]
+    fn expand_edge_filter_prevents_crossing_any_edge_it_rejects() {
+        let g = diamond_graph().into_dynamic();
+        let roots = vec![g.vertex("a").unwrap()];
+
+        Python::with_gil(|py| {
+            let reject_all: PyObject = py.eval("lambda e: False", None, None).unwrap().into();
+            let reached = expand_from(roots, 2, "out", Some(reject_all), None).unwrap();
+            assert!(reached.is_empty());
+        });
+    }
+
+    #[test]
+    fn traversal_iterator_lazily_merges_multiple_roots_into_one_walk() {
+        // backs PyPathFromVertex::bfs/dfs, which seed the walk with every vertex already on the
+        // path rather than a single root.
+        let g = diamond_graph().into_dynamic();
+        let roots = vec![g.vertex("b").unwrap(), g.vertex("c").unwrap()];
+
+        let iter = traversal_iterator(roots, None, "out", true).unwrap();
+        let names: HashSet<String> = iter.into_iter().map(|v| v.name()).collect();
+        // "d" is reachable from both roots but must still only be yielded once.
+        assert_eq!(names, HashSet::from(["b".to_string(), "c".to_string(), "d".to_string()]));
+    }
+
+    #[test]
+    fn traversal_iterator_is_lazy_and_only_does_work_once_driven() {
+        // Constructing the iterator must not itself walk the graph — BoundedWalk::next does all
+        // the work one vertex at a time, so nothing is visited until the caller pulls from it.
+        let g = diamond_graph().into_dynamic();
+        let mut iter = traversal_iterator(vec![g.vertex("a").unwrap()], None, "out", true)
+            .unwrap()
+            .into_iter();
+        assert_eq!(iter.next().map(|v| v.name()), Some("a".to_string()));
+        let rest: HashSet<String> = iter.map(|v| v.name()).collect();
+        assert_eq!(rest, HashSet::from(["b".to_string(), "c".to_string(), "d".to_string()]));
+    }
+
+    #[test]
+    fn causal_root_picks_earliest_time_for_descendants_and_latest_for_ancestors() {
+        let g = Graph::new();
+        g.add_vertex(3, "a", NO_PROPS).unwrap();
+        g.add_vertex(7, "a", NO_PROPS).unwrap();
+        let v = g.into_dynamic().vertex("a").unwrap();
+
+        let (root, t) = causal_root(&v, true).unwrap();
+        assert_eq!((root.name(), t), ("a".to_string(), 3));
+
+        let (root, t) = causal_root(&v, false).unwrap();
+        assert_eq!((root.name(), t), ("a".to_string(), 7));
+    }
+
+    #[test]
+    fn causal_iter_walks_descendants_strictly_forward_in_time_without_revisiting() {
+        let g = chain_graph().into_dynamic();
+        let a = g.vertex("a").unwrap();
+        let root = causal_root(&a, true).unwrap();
+
+        let walked: Vec<(String, i64)> = CausalIter::new(vec![root], true)
+            .map(|(v, t)| (v.name(), t))
+            .collect();
+        assert_eq!(
+            walked,
+            vec![("a".to_string(), 0), ("b".to_string(), 1), ("c".to_string(), 5)]
+        );
+    }
+
+    #[test]
+    fn causal_iter_walks_ancestors_strictly_backward_in_time() {
+        let g = chain_graph().into_dynamic();
+        let a = g.vertex("a").unwrap();
+        // "a"'s own activity spans [0, 1] (via "d -> a" and "a -> b"), so its latest time (the
+        // ancestor walk's reference point) is 1, not 0.
+        let root = causal_root(&a, false).unwrap();
+        assert_eq!(root.1, 1);
+
+        let walked: Vec<(String, i64)> = CausalIter::new(vec![root], false)
+            .map(|(v, t)| (v.name(), t))
+            .collect();
+        assert_eq!(walked, vec![("a".to_string(), 1), ("d".to_string(), 0)]);
+    }
+
+    #[test]
+    fn causal_iter_does_not_settle_a_vertex_on_a_stale_arrival_time() {
+        // a -> b @ t=10 is a direct but slow route; a -> c @ t=1, c -> b @ t=2 is a longer but
+        // much earlier-arriving route to the same vertex "b". "b" is reached via the direct edge
+        // first (both are enqueued from "a" at once, but FIFO order would expand "a -> b" before
+        // the two-hop route even gets a chance to relax "b"), so a FIFO frontier with settle-once
+        // semantics yields "b" at t=10 and never revisits it once the better t=2 arrival is later
+        // discovered via "c". A priority queue instead defers yielding "b" until its true
+        // most-admissible arrival (t=2) has been found.
+        let g = Graph::new();
+        // "a" itself exists from t=0, so both outgoing edges below (at t=1 and t=10) are usable
+        // from the root's reference time.
+        g.add_vertex(0, "a", NO_PROPS).unwrap();
+        g.add_edge(10, "a", "b", NO_PROPS, None).unwrap();
+        g.add_edge(1, "a", "c", NO_PROPS, None).unwrap();
+        g.add_edge(2, "c", "b", NO_PROPS, None).unwrap();
+        let g = g.into_dynamic();
+
+        let a = g.vertex("a").unwrap();
+        let root = causal_root(&a, true).unwrap();
+
+        let walked: HashMap<String, i64> = CausalIter::new(vec![root], true)
+            .map(|(v, t)| (v.name(), t))
+            .collect();
+        assert_eq!(walked.get("b"), Some(&2));
+    }
+
+    /// `a -> b` (weight 1), `a -> c` (weight 5), `c -> d` (weight 1), `b -> d` (weight 1), so the
+    /// cheap route `a -> b -> d` (cost 2) undercuts the direct-looking `a -> c -> d` (cost 6).
+    fn weighted_diamond_graph() -> Graph {
+        let g = Graph::new();
+        g.add_edge(0, "a", "b", [("weight".to_string(), Prop::F64(1.0))], None)
+            .unwrap();
+        g.add_edge(0, "a", "c", [("weight".to_string(), Prop::F64(5.0))], None)
+            .unwrap();
+        g.add_edge(0, "b", "d", [("weight".to_string(), Prop::F64(1.0))], None)
+            .unwrap();
+        g.add_edge(0, "c", "d", [("weight".to_string(), Prop::F64(1.0))], None)
+            .unwrap();
+        g
+    }
+
+    #[test]
+    fn dijkstra_finds_the_cheapest_weighted_route() {
+        let g = weighted_diamond_graph().into_dynamic();
+        let target = g.vertex("d").unwrap().id();
+
+        let (path, cost) =
+            dijkstra_shortest_path(vec![g.vertex("a").unwrap()], target, "weight", "out")
+                .unwrap()
+                .unwrap();
+        let names: Vec<String> = path.into_iter().map(|v| v.name()).collect();
+        assert_eq!(names, vec!["a".to_string(), "b".to_string(), "d".to_string()]);
+        assert_eq!(cost, 2.0);
+    }
+
+    #[test]
+    fn dijkstra_falls_back_to_hop_cost_when_the_weight_property_is_missing() {
+        let g = Graph::new();
+        g.add_edge(0, "a", "b", NO_PROPS, None).unwrap();
+        g.add_edge(0, "b", "c", NO_PROPS, None).unwrap();
+        let g = g.into_dynamic();
+        let target = g.vertex("c").unwrap().id();
+
+        let (path, cost) =
+            dijkstra_shortest_path(vec![g.vertex("a").unwrap()], target, "weight", "out")
+                .unwrap()
+                .unwrap();
+        assert_eq!(path.len(), 3);
+        assert_eq!(cost, 2.0);
+    }
+
+    #[test]
+    fn dijkstra_returns_none_when_the_target_is_unreachable() {
+        let g = weighted_diamond_graph().into_dynamic();
+        let unreachable_target = g.vertex("a").unwrap().id() + 1000;
+
+        let result =
+            dijkstra_shortest_path(vec![g.vertex("a").unwrap()], unreachable_target, "weight", "out")
+                .unwrap();
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn dijkstra_rejects_an_unknown_direction() {
+        let g = weighted_diamond_graph().into_dynamic();
+        let target = g.vertex("d").unwrap().id();
+        assert!(dijkstra_shortest_path(
+            vec![g.vertex("a").unwrap()],
+            target,
+            "weight",
+            "sideways"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn astar_with_a_zero_heuristic_finds_the_same_path_as_dijkstra() {
+        let g = weighted_diamond_graph().into_dynamic();
+        let root = g.vertex("a").unwrap();
+        let target = g.vertex("d").unwrap().id();
+
+        Python::with_gil(|py| {
+            let zero: PyObject = py.eval("lambda v: 0.0", None, None).unwrap().into();
+            let (path, cost) =
+                astar_search(root, target, "weight", zero, "out", true).unwrap().unwrap();
+            let names: Vec<String> = path.into_iter().map(|v| v.name()).collect();
+            assert_eq!(names, vec!["a".to_string(), "b".to_string(), "d".to_string()]);
+            assert_eq!(cost, 2.0);
+        });
+    }
+
+    #[test]
+    fn astar_returns_none_when_the_target_is_unreachable() {
+        let g = weighted_diamond_graph().into_dynamic();
+        let root = g.vertex("a").unwrap();
+        let unreachable_target = root.id() + 1000;
+
+        Python::with_gil(|py| {
+            let zero: PyObject = py.eval("lambda v: 0.0", None, None).unwrap().into();
+            let result =
+                astar_search(root, unreachable_target, "weight", zero, "out", true).unwrap();
+            assert!(result.is_none());
+        });
+    }
+
+    #[test]
+    fn astar_rejects_an_unknown_direction_without_touching_the_heuristic() {
+        let g = weighted_diamond_graph().into_dynamic();
+        let root = g.vertex("a").unwrap();
+        let target = g.vertex("d").unwrap().id();
+
+        Python::with_gil(|py| {
+            let panics: PyObject = py
+                .eval("lambda v: 1 / 0", None, None)
+                .unwrap()
+                .into();
+            assert!(astar_search(root, target, "weight", panics, "sideways", true).is_err());
+        });
+    }
+
+    fn vertex_iterable(g: &DynamicGraph) -> PyVertexIterable {
+        let vertices = Vertices::new(g.clone());
+        (move || vertices.clone()).into()
+    }
+
+    #[test]
+    fn vertex_iterable_count_and_sum_degree_stream_rather_than_collect() {
+        let g = diamond_graph().into_dynamic();
+        let iterable = vertex_iterable(&g);
+        assert_eq!(iterable.count(), 4);
+        // each vertex's (in + out) degree is 2, for a total of 8 across all four vertices.
+        assert_eq!(iterable.sum_degree(), 8);
+    }
+
+    #[test]
+    fn vertex_iterable_min_and_max_time_span_every_vertexs_activity() {
+        let g = chain_graph().into_dynamic();
+        let iterable = vertex_iterable(&g);
+        assert_eq!(iterable.min_time(), Some(0));
+        assert_eq!(iterable.max_time(), Some(5));
+    }
+
+    #[test]
+    fn vertex_iterable_aggregate_property_reduces_over_present_numeric_values_only() {
+        let g = Graph::new();
+        g.add_vertex(0, "a", NO_PROPS).unwrap();
+        g.add_vertex(0, "b", NO_PROPS).unwrap();
+        g.add_vertex(0, "c", NO_PROPS).unwrap();
+        g.vertex("a")
+            .unwrap()
+            .add_constant_properties(HashMap::from([("score".to_string(), Prop::F64(10.0))]))
+            .unwrap();
+        g.vertex("b")
+            .unwrap()
+            .add_constant_properties(HashMap::from([("score".to_string(), Prop::F64(20.0))]))
+            .unwrap();
+        // "c" has no "score" at all, and must be skipped rather than treated as 0.
+
+        let iterable = vertex_iterable(&g.into_dynamic());
+        assert_eq!(iterable.aggregate_property("score", "sum").unwrap(), Some(30.0));
+        assert_eq!(iterable.aggregate_property("score", "min").unwrap(), Some(10.0));
+        assert_eq!(iterable.aggregate_property("score", "max").unwrap(), Some(20.0));
+        assert_eq!(iterable.aggregate_property("score", "mean").unwrap(), Some(15.0));
+        assert!(iterable.aggregate_property("score", "median").is_err());
+    }
+
+    #[test]
+    fn vertex_iterable_aggregate_property_is_none_when_nothing_has_the_property() {
+        let g = Graph::new();
+        g.add_vertex(0, "a", NO_PROPS).unwrap();
+        let iterable = vertex_iterable(&g.into_dynamic());
+        assert_eq!(iterable.aggregate_property("score", "sum").unwrap(), Some(0.0));
+        assert_eq!(iterable.aggregate_property("score", "max").unwrap(), None);
+    }
 }